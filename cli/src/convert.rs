@@ -0,0 +1,189 @@
+//! Conversion of JSON-RPC responses into the `sf.solana.type.v1` model the substreams consume.
+
+use anyhow::{anyhow, Context, Error};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde_json::Value;
+use substreams_solana::pb::sf::solana::r#type::v1 as pb;
+
+use solana_substreams_common::transaction_error::{
+    BORSH_IO_ERROR_VARIANT, CUSTOM_ERROR_VARIANT, INSTRUCTION_ERRORS, INSTRUCTION_ERROR_VARIANT, TRANSACTION_ERRORS,
+};
+
+use crate::rpc::*;
+
+pub fn convert_block(block: RpcBlock, slot: u64) -> Result<pb::Block, Error> {
+    Ok(pb::Block {
+        previous_blockhash: block.previous_blockhash,
+        blockhash: block.blockhash,
+        parent_slot: block.parent_slot,
+        transactions: block.transactions.into_iter().map(convert_transaction).collect::<Result<_, _>>()?,
+        block_time: block.block_time.map(|timestamp| pb::UnixTimestamp { timestamp }),
+        block_height: block.block_height.map(|block_height| pb::BlockHeight { block_height }),
+        slot,
+        ..Default::default()
+    })
+}
+
+pub fn convert_transaction(transaction: RpcTransaction) -> Result<pb::ConfirmedTransaction, Error> {
+    let versioned = transaction.version.as_ref().map_or(false, |x| x.is_number());
+    let message = transaction.transaction.message;
+    Ok(pb::ConfirmedTransaction {
+        transaction: Some(pb::Transaction {
+            signatures: transaction.transaction.signatures.iter().map(|x| decode_base58(x)).collect::<Result<_, _>>()?,
+            message: Some(pb::Message {
+                header: Some(pb::MessageHeader {
+                    num_required_signatures: message.header.num_required_signatures,
+                    num_readonly_signed_accounts: message.header.num_readonly_signed_accounts,
+                    num_readonly_unsigned_accounts: message.header.num_readonly_unsigned_accounts,
+                }),
+                account_keys: message.account_keys.iter().map(|x| decode_base58(x)).collect::<Result<_, _>>()?,
+                recent_blockhash: decode_base58(&message.recent_blockhash)?,
+                instructions: message.instructions.into_iter().map(convert_compiled_instruction).collect::<Result<_, _>>()?,
+                versioned,
+                address_table_lookups: message.address_table_lookups.into_iter().map(convert_address_table_lookup).collect::<Result<_, _>>()?,
+            }),
+        }),
+        meta: transaction.meta.map(convert_meta).transpose()?,
+    })
+}
+
+fn convert_meta(meta: RpcMeta) -> Result<pb::TransactionStatusMeta, Error> {
+    let loaded_addresses = meta.loaded_addresses.unwrap_or(RpcLoadedAddresses { writable: Vec::new(), readonly: Vec::new() });
+    Ok(pb::TransactionStatusMeta {
+        err: meta.err.as_ref().map(encode_transaction_error).transpose()?.map(|err| pb::TransactionError { err }),
+        fee: meta.fee,
+        pre_balances: meta.pre_balances,
+        post_balances: meta.post_balances,
+        inner_instructions_none: meta.inner_instructions.is_none(),
+        inner_instructions: meta.inner_instructions.unwrap_or_default().into_iter().map(convert_inner_instructions).collect::<Result<_, _>>()?,
+        log_messages_none: meta.log_messages.is_none(),
+        log_messages: meta.log_messages.unwrap_or_default(),
+        pre_token_balances: meta.pre_token_balances.unwrap_or_default().into_iter().map(convert_token_balance).collect(),
+        post_token_balances: meta.post_token_balances.unwrap_or_default().into_iter().map(convert_token_balance).collect(),
+        loaded_writable_addresses: loaded_addresses.writable.iter().map(|x| decode_base58(x)).collect::<Result<_, _>>()?,
+        loaded_readonly_addresses: loaded_addresses.readonly.iter().map(|x| decode_base58(x)).collect::<Result<_, _>>()?,
+        return_data_none: meta.return_data.is_none(),
+        return_data: meta.return_data.map(convert_return_data).transpose()?,
+        compute_units_consumed: meta.compute_units_consumed,
+        ..Default::default()
+    })
+}
+
+fn convert_compiled_instruction(instruction: RpcInstruction) -> Result<pb::CompiledInstruction, Error> {
+    Ok(pb::CompiledInstruction {
+        program_id_index: instruction.program_id_index,
+        accounts: instruction.accounts,
+        data: decode_base58(&instruction.data)?,
+    })
+}
+
+fn convert_inner_instructions(inner_instructions: RpcInnerInstructions) -> Result<pb::InnerInstructions, Error> {
+    Ok(pb::InnerInstructions {
+        index: inner_instructions.index,
+        instructions: inner_instructions.instructions.into_iter().map(|instruction| {
+            Ok(pb::InnerInstruction {
+                program_id_index: instruction.program_id_index,
+                accounts: instruction.accounts,
+                data: decode_base58(&instruction.data)?,
+                stack_height: instruction.stack_height,
+            })
+        }).collect::<Result<_, Error>>()?,
+    })
+}
+
+fn convert_address_table_lookup(lookup: RpcAddressTableLookup) -> Result<pb::MessageAddressTableLookup, Error> {
+    Ok(pb::MessageAddressTableLookup {
+        account_key: decode_base58(&lookup.account_key)?,
+        writable_indexes: lookup.writable_indexes,
+        readonly_indexes: lookup.readonly_indexes,
+    })
+}
+
+fn convert_token_balance(balance: RpcTokenBalance) -> pb::TokenBalance {
+    pb::TokenBalance {
+        account_index: balance.account_index,
+        mint: balance.mint,
+        ui_token_amount: Some(pb::UiTokenAmount {
+            ui_amount: balance.ui_token_amount.ui_amount.unwrap_or_default(),
+            decimals: balance.ui_token_amount.decimals,
+            amount: balance.ui_token_amount.amount,
+            ui_amount_string: balance.ui_token_amount.ui_amount_string,
+        }),
+        owner: balance.owner.unwrap_or_default(),
+        program_id: balance.program_id.unwrap_or_default(),
+    }
+}
+
+fn convert_return_data(return_data: RpcReturnData) -> Result<pb::ReturnData, Error> {
+    let (data, encoding) = return_data.data;
+    if encoding != "base64" {
+        return Err(anyhow!("Unsupported return data encoding `{}`", encoding));
+    }
+    Ok(pb::ReturnData {
+        program_id: decode_base58(&return_data.program_id)?,
+        data: STANDARD.decode(data).context("Invalid return data")?,
+    })
+}
+
+/// Encodes the JSON representation of a `TransactionError` into its bincode serialization, as
+/// stored in the transaction metadata.
+pub fn encode_transaction_error(error: &Value) -> Result<Vec<u8>, Error> {
+    let (name, payload) = get_variant(error)?;
+    let variant = get_variant_index(TRANSACTION_ERRORS, name)?;
+    let mut data = variant.to_le_bytes().to_vec();
+
+    match (variant, payload) {
+        (INSTRUCTION_ERROR_VARIANT, Some(Value::Array(payload))) if payload.len() == 2 => {
+            data.push(get_u8(&payload[0])?);
+            let (name, payload) = get_variant(&payload[1])?;
+            let instruction_variant = get_variant_index(INSTRUCTION_ERRORS, name)?;
+            data.extend(instruction_variant.to_le_bytes());
+            match (instruction_variant, payload) {
+                (CUSTOM_ERROR_VARIANT, Some(code)) => {
+                    let code = code.as_u64().and_then(|x| u32::try_from(x).ok()).ok_or_else(|| anyhow!("Invalid custom error code {}", code))?;
+                    data.extend(code.to_le_bytes());
+                }
+                (BORSH_IO_ERROR_VARIANT, Some(Value::String(message))) => {
+                    data.extend((message.len() as u64).to_le_bytes());
+                    data.extend(message.as_bytes());
+                }
+                _ => (),
+            }
+        }
+        (_, Some(Value::Object(fields))) => {
+            // `InsufficientFundsForRent` and `ProgramExecutionTemporarilyRestricted`.
+            let account_index = fields.get("account_index").ok_or_else(|| anyhow!("Unexpected error {}", error))?;
+            data.push(get_u8(account_index)?);
+        }
+        // `DuplicateInstruction`.
+        (_, Some(index)) => data.push(get_u8(index)?),
+        (_, None) => (),
+    }
+    Ok(data)
+}
+
+/// Splits an externally tagged enum value into its variant name and payload.
+fn get_variant(value: &Value) -> Result<(&str, Option<&Value>), Error> {
+    match value {
+        Value::String(name) => Ok((name, None)),
+        Value::Object(fields) if fields.len() == 1 => fields
+            .iter()
+            .next()
+            .map(|(name, payload)| (name.as_str(), Some(payload)))
+            .ok_or_else(|| anyhow!("Unexpected error {}", value)),
+        _ => Err(anyhow!("Unexpected error {}", value)),
+    }
+}
+
+fn get_variant_index(names: &[&str], name: &str) -> Result<u32, Error> {
+    names.iter().position(|x| *x == name).map(|x| x as u32).ok_or_else(|| anyhow!("Unknown error variant `{}`", name))
+}
+
+fn get_u8(value: &Value) -> Result<u8, Error> {
+    value.as_u64().and_then(|x| u8::try_from(x).ok()).ok_or_else(|| anyhow!("Expected a u8, found {}", value))
+}
+
+fn decode_base58(value: &str) -> Result<Vec<u8>, Error> {
+    bs58::decode(value).into_vec().with_context(|| format!("Invalid base58 string `{}`", value))
+}
@@ -0,0 +1,172 @@
+//! Decodes a saved Solana JSON-RPC `getTransaction` or `getBlock` response with the substreams of
+//! this repository, and prints the resulting events as JSON.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Error};
+use clap::{Parser, ValueEnum};
+use prost::Message;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction, UnixTimestamp};
+use substreams_solana_utils::transaction::get_signature;
+
+use solana_substreams_common::failure::ParseFailures;
+use solana_substreams_common::params::ErrorPolicy;
+use solana_substreams_common::transaction_error::get_transaction_error;
+
+mod convert;
+mod rpc;
+
+#[derive(Parser)]
+#[command(about = "Decodes a saved getTransaction or getBlock JSON-RPC response into events")]
+struct Args {
+    /// The saved response, either the whole JSON-RPC response or its `result`. It must have been
+    /// requested with `json` encoding.
+    file: PathBuf,
+    /// Decoder to run, can be repeated. Runs all of them by default.
+    #[arg(short, long, value_enum)]
+    program: Vec<Program>,
+    /// Slot of a `getBlock` response, which doesn't include it. Defaults to the parent slot + 1.
+    #[arg(long)]
+    slot: Option<u64>,
+    /// Also writes the converted block as a fixture for the golden tests.
+    #[arg(long)]
+    save_fixture: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Program {
+    SystemProgram,
+    SplToken,
+    RaydiumAmm,
+    Pumpfun,
+    MplTokenMetadata,
+}
+
+impl Program {
+    fn name(&self) -> &'static str {
+        match self {
+            Program::SystemProgram => "system_program",
+            Program::SplToken => "spl_token",
+            Program::RaydiumAmm => "raydium_amm",
+            Program::Pumpfun => "pumpfun",
+            Program::MplTokenMetadata => "mpl_token_metadata",
+        }
+    }
+}
+
+fn main() -> Result<(), Error> {
+    let args = Args::parse();
+    let programs = match args.program.is_empty() {
+        true => Program::value_variants().to_vec(),
+        false => args.program,
+    };
+
+    let data = fs::read_to_string(&args.file).with_context(|| format!("Failed to read {}", args.file.display()))?;
+    let block = load_block(&data, args.slot)?;
+
+    if let Some(path) = args.save_fixture {
+        fs::write(&path, block.encode_to_vec()).with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    let transactions = block.transactions
+        .iter()
+        .map(|transaction| decode_transaction(transaction, &programs))
+        .collect::<Result<Vec<_>, _>>()?;
+    println!("{}", serde_json::to_string_pretty(&transactions)?);
+    Ok(())
+}
+
+/// Loads the response as a block, wrapping a single transaction in a block of its own.
+fn load_block(data: &str, slot: Option<u64>) -> Result<Block, Error> {
+    let mut value: Value = serde_json::from_str(data).context("Invalid JSON")?;
+    if let Some(result) = value.get_mut("result") {
+        value = result.take();
+    }
+    if value.get("transaction").map_or(false, |x| !x.is_object()) {
+        return Err(anyhow!("Only `json` encoded responses are supported"));
+    }
+
+    if value.get("transactions").is_some() {
+        let block: rpc::RpcBlock = serde_json::from_value(value).context("Invalid getBlock response")?;
+        let slot = slot.unwrap_or(block.parent_slot + 1);
+        convert::convert_block(block, slot)
+    } else if value.get("transaction").is_some() {
+        let transaction: rpc::RpcTransaction = serde_json::from_value(value).context("Invalid getTransaction response")?;
+        let slot = slot.or(transaction.slot).unwrap_or_default();
+        let block_time = transaction.block_time;
+        Ok(Block {
+            transactions: vec![convert::convert_transaction(transaction)?],
+            block_time: block_time.map(|timestamp| UnixTimestamp { timestamp }),
+            slot,
+            ..Default::default()
+        })
+    } else {
+        Err(anyhow!("Expected a getTransaction or getBlock response"))
+    }
+}
+
+fn decode_transaction(transaction: &ConfirmedTransaction, programs: &[Program]) -> Result<Value, Error> {
+    let mut failures = ParseFailures::new(ErrorPolicy::Report);
+    let mut events = Map::new();
+    for program in programs {
+        let program_events = match program {
+            Program::SystemProgram => decode(
+                transaction,
+                &mut failures,
+                system_program_substream::parse_transaction,
+                system_program_substream::parse_failed_transaction,
+            ),
+            Program::SplToken => decode(
+                transaction,
+                &mut failures,
+                spl_token_substream::parse_transaction,
+                spl_token_substream::parse_failed_transaction,
+            ),
+            Program::RaydiumAmm => decode(
+                transaction,
+                &mut failures,
+                raydium_amm_substream::parse_transaction,
+                raydium_amm_substream::parse_failed_transaction,
+            ),
+            Program::Pumpfun => decode(
+                transaction,
+                &mut failures,
+                pumpfun_substream::parse_transaction,
+                pumpfun_substream::parse_failed_transaction,
+            ),
+            Program::MplTokenMetadata => decode(
+                transaction,
+                &mut failures,
+                mpl_token_metadata_substream::parse_transaction,
+                mpl_token_metadata_substream::parse_failed_transaction,
+            ),
+        }?;
+        events.insert(program.name().to_string(), program_events);
+    }
+
+    Ok(json!({
+        "signature": get_signature(transaction),
+        "error": get_transaction_error(transaction),
+        "events": events,
+        "parse_failures": failures.into_failures(),
+    }))
+}
+
+/// Runs a decoder over the transaction. Instructions of failed transactions that cannot be parsed
+/// are reported like the others, as in the map modules.
+fn decode<T: Serialize>(
+    transaction: &ConfirmedTransaction,
+    failures: &mut ParseFailures,
+    parse_transaction: fn(&ConfirmedTransaction, &mut ParseFailures) -> Result<Vec<T>, Error>,
+    parse_failed_transaction: fn(&ConfirmedTransaction, &mut ParseFailures) -> Result<Vec<T>, Error>,
+) -> Result<Value, Error> {
+    let failed = transaction.meta.as_ref().map_or(false, |meta| meta.err.is_some());
+    let events = match failed {
+        true => parse_failed_transaction(transaction, failures)?,
+        false => parse_transaction(transaction, failures)?,
+    };
+    Ok(serde_json::to_value(events)?)
+}
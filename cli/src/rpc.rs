@@ -0,0 +1,127 @@
+//! Solana JSON-RPC `getTransaction` and `getBlock` responses, as returned with `json` encoding.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcBlock {
+    pub block_height: Option<u64>,
+    pub block_time: Option<i64>,
+    pub blockhash: String,
+    pub parent_slot: u64,
+    pub previous_blockhash: String,
+    #[serde(default)]
+    pub transactions: Vec<RpcTransaction>,
+}
+
+/// A `getTransaction` result, or an entry of `getBlock`'s `transactions`, which carries neither
+/// `slot` nor `blockTime`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransaction {
+    pub slot: Option<u64>,
+    pub block_time: Option<i64>,
+    pub transaction: RpcTransactionBody,
+    pub meta: Option<RpcMeta>,
+    /// Either `"legacy"` or a version number.
+    pub version: Option<Value>,
+}
+
+#[derive(Deserialize)]
+pub struct RpcTransactionBody {
+    pub signatures: Vec<String>,
+    pub message: RpcMessage,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMessage {
+    pub account_keys: Vec<String>,
+    pub header: RpcMessageHeader,
+    pub recent_blockhash: String,
+    pub instructions: Vec<RpcInstruction>,
+    #[serde(default)]
+    pub address_table_lookups: Vec<RpcAddressTableLookup>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMessageHeader {
+    pub num_required_signatures: u32,
+    pub num_readonly_signed_accounts: u32,
+    pub num_readonly_unsigned_accounts: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcInstruction {
+    pub program_id_index: u32,
+    pub accounts: Vec<u8>,
+    /// Base58 encoded.
+    pub data: String,
+    pub stack_height: Option<u32>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcAddressTableLookup {
+    pub account_key: String,
+    pub writable_indexes: Vec<u8>,
+    pub readonly_indexes: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMeta {
+    pub err: Option<Value>,
+    pub fee: u64,
+    pub pre_balances: Vec<u64>,
+    pub post_balances: Vec<u64>,
+    pub inner_instructions: Option<Vec<RpcInnerInstructions>>,
+    pub log_messages: Option<Vec<String>>,
+    pub pre_token_balances: Option<Vec<RpcTokenBalance>>,
+    pub post_token_balances: Option<Vec<RpcTokenBalance>>,
+    pub loaded_addresses: Option<RpcLoadedAddresses>,
+    pub return_data: Option<RpcReturnData>,
+    pub compute_units_consumed: Option<u64>,
+}
+
+#[derive(Deserialize)]
+pub struct RpcInnerInstructions {
+    pub index: u32,
+    pub instructions: Vec<RpcInstruction>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTokenBalance {
+    pub account_index: u32,
+    pub mint: String,
+    pub owner: Option<String>,
+    pub program_id: Option<String>,
+    pub ui_token_amount: RpcUiTokenAmount,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcUiTokenAmount {
+    pub amount: String,
+    pub decimals: u32,
+    pub ui_amount: Option<f64>,
+    pub ui_amount_string: String,
+}
+
+#[derive(Deserialize)]
+pub struct RpcLoadedAddresses {
+    pub writable: Vec<String>,
+    pub readonly: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcReturnData {
+    pub program_id: String,
+    /// `[data, "base64"]`.
+    pub data: (String, String),
+}
@@ -0,0 +1,36 @@
+use substreams_solana_utils as utils;
+use utils::instruction::StructuredInstruction;
+use utils::transaction::TransactionContext;
+
+use crate::pb::solana_common::{AccountBalance, TokenAccount};
+
+/// Returns the lamport balances of the account at `index` in the instruction's accounts.
+pub fn get_account_balance(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    index: usize,
+) -> Option<AccountBalance> {
+    let account_index = *instruction.instruction.accounts().get(index)?;
+    context.account_balances.get(account_index as usize).map(|x| x.clone().into())
+}
+
+impl<'a> From<&'a utils::spl_token::TokenAccount<'a>> for TokenAccount {
+    fn from(value: &'a utils::spl_token::TokenAccount<'a>) -> Self {
+        Self {
+            address: value.address.to_string(),
+            owner: value.owner.to_string(),
+            mint: value.mint.to_string(),
+            pre_balance: value.pre_balance,
+            post_balance: value.post_balance,
+        }
+    }
+}
+
+impl From<utils::account::AccountBalance> for AccountBalance {
+    fn from(value: utils::account::AccountBalance) -> Self {
+        Self {
+            pre_balance: value.pre_balance,
+            post_balance: value.post_balance,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+use substreams_solana_utils::transaction::get_signature;
+
+use crate::envelope::get_transaction_envelope;
+use crate::failure::ParseFailures;
+use crate::params::Params;
+use crate::pb::solana_common::{TransactionEnvelope, TransactionError};
+
+/// The events parsed from a single transaction, before being wrapped in the module's own
+/// `*TransactionEvents` message.
+pub struct ParsedTransaction<T> {
+    pub signature: String,
+    pub events: Vec<T>,
+    pub envelope: TransactionEnvelope,
+    pub error: Option<TransactionError>,
+}
+
+/// Runs `parse_transaction` over every transaction of the block, and keeps those with events.
+///
+/// Failed transactions are skipped unless `include_failed_transactions` is set, in which case
+/// they are parsed with their instruction failures handed to `failures` without failing the
+/// block, and kept even without events if the error was raised by one of `program_ids`.
+/// `get_error` decodes the transaction error, naming custom errors of the module's program.
+pub fn parse_block<T, E, F>(
+    block: &Block,
+    params: &Params,
+    failures: &mut ParseFailures,
+    program_ids: &[String],
+    get_error: E,
+    mut parse_transaction: F,
+) -> Result<Vec<ParsedTransaction<T>>, Error>
+where
+    E: Fn(&ConfirmedTransaction) -> Option<TransactionError>,
+    F: FnMut(&ConfirmedTransaction, &mut ParseFailures) -> Result<Vec<T>, Error>,
+{
+    let mut transactions = Vec::new();
+    for (i, transaction) in block.transactions.iter().enumerate() {
+        if transaction.meta.is_none() {
+            continue;
+        }
+        let error = get_error(transaction);
+        let events = match error {
+            None => parse_transaction(transaction, failures)?,
+            Some(_) if params.include_failed_transactions => {
+                failures.without_failing(|failures| parse_transaction(transaction, failures))?
+            }
+            Some(_) => continue,
+        };
+        let raised_by_program = error.as_ref().map_or(false, |x| program_ids.iter().any(|id| x.is_raised_by(id)));
+        if !events.is_empty() || raised_by_program {
+            transactions.push(ParsedTransaction {
+                signature: get_signature(transaction),
+                events,
+                envelope: get_transaction_envelope(block, i, transaction),
+                error,
+            });
+        }
+    }
+    Ok(transactions)
+}
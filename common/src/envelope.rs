@@ -0,0 +1,45 @@
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+
+use crate::pb::solana_common::{BlockEnvelope, TransactionEnvelope};
+
+pub fn get_block_envelope(block: &Block) -> BlockEnvelope {
+    BlockEnvelope {
+        slot: block.slot,
+        parent_slot: block.parent_slot,
+        blockhash: block.blockhash.clone(),
+        block_time: block.block_time.as_ref().map(|x| x.timestamp),
+        block_height: block.block_height.as_ref().map(|x| x.block_height),
+    }
+}
+
+/// Builds the envelope of the transaction at `transaction_index` in `block.transactions`.
+pub fn get_transaction_envelope(
+    block: &Block,
+    transaction_index: usize,
+    transaction: &ConfirmedTransaction,
+) -> TransactionEnvelope {
+    let signers = get_signers(transaction);
+    let fee_payer = signers.first().cloned().unwrap_or_default();
+
+    TransactionEnvelope {
+        slot: block.slot,
+        block_time: block.block_time.as_ref().map(|x| x.timestamp),
+        transaction_index: transaction_index as u32,
+        fee_payer,
+        signers,
+    }
+}
+
+/// Returns the transaction signers, fee payer first.
+pub fn get_signers(transaction: &ConfirmedTransaction) -> Vec<String> {
+    let message = match transaction.transaction.as_ref().and_then(|x| x.message.as_ref()) {
+        Some(message) => message,
+        None => return Vec::new(),
+    };
+    let num_required_signatures = message.header.as_ref().map_or(0, |x| x.num_required_signatures as usize);
+    message.account_keys
+        .iter()
+        .take(num_required_signatures)
+        .map(|x| bs58::encode(x).into_string())
+        .collect()
+}
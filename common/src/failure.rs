@@ -0,0 +1,67 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Error};
+
+use crate::params::ErrorPolicy;
+use crate::pb::solana_common::{InstructionPosition, ParseFailure};
+
+/// Collects the parse failures of a block according to an [`ErrorPolicy`].
+#[derive(Debug, Clone)]
+pub struct ParseFailures {
+    policy: ErrorPolicy,
+    failures: Vec<ParseFailure>,
+    count: u32,
+}
+
+impl ParseFailures {
+    pub fn new(policy: ErrorPolicy) -> Self {
+        Self { policy, failures: Vec::new(), count: 0 }
+    }
+
+    /// Records a failure. Returns an error under the `strict` policy, which callers propagate to
+    /// fail the block.
+    pub fn record<E: Display>(
+        &mut self,
+        signature: &str,
+        position: Option<InstructionPosition>,
+        error: E,
+    ) -> Result<(), Error> {
+        match self.policy {
+            ErrorPolicy::Strict => Err(anyhow!("Failed to parse transaction {}: {}", signature, error)),
+            ErrorPolicy::Skip => {
+                self.count += 1;
+                Ok(())
+            }
+            ErrorPolicy::Report => {
+                self.count += 1;
+                self.failures.push(ParseFailure {
+                    signature: signature.to_string(),
+                    position,
+                    error: error.to_string(),
+                });
+                Ok(())
+            }
+        }
+    }
+
+    /// Runs `parse` with failures that don't fail the block: under the `strict` policy they are
+    /// counted as under `skip`. Used for failed transactions, whose instructions may reference
+    /// state that was never produced.
+    pub fn without_failing<T, F: FnOnce(&mut Self) -> T>(&mut self, parse: F) -> T {
+        let policy = self.policy;
+        if policy == ErrorPolicy::Strict {
+            self.policy = ErrorPolicy::Skip;
+        }
+        let result = parse(self);
+        self.policy = policy;
+        result
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn into_failures(self) -> Vec<ParseFailure> {
+        self.failures
+    }
+}
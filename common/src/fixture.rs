@@ -0,0 +1,82 @@
+//! Golden tests over captured blocks.
+//!
+//! A fixture is a prost-encoded `sf.solana.type.v1.Block` stored as `<name>.binpb` in a crate's
+//! fixtures directory, next to the expected output of its decoder in `<name>.golden`. Running the
+//! tests with `UPDATE_GOLDENS=1` rewrites the goldens instead of comparing them, so decoder
+//! changes show up as diffs of the checked-in goldens.
+
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Error;
+use prost::Message;
+use similar::TextDiff;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use crate::params::{ErrorPolicy, Params};
+
+const FIXTURE_EXTENSION: &str = "binpb";
+const GOLDEN_EXTENSION: &str = "golden";
+
+/// Runs `parse` over every fixture in `dir`, with failed transactions included and
+/// `error_policy=report`, and checks its output against the golden. Panics with a diff of every
+/// mismatching golden, and when `dir` has no fixtures.
+pub fn run_fixtures<T: Debug, F: Fn(&Block, &Params) -> Result<T, Error>>(dir: &str, parse: F) {
+    let params = Params {
+        include_failed_transactions: true,
+        error_policy: ErrorPolicy::Report,
+    };
+    let update = std::env::var_os("UPDATE_GOLDENS").is_some();
+    let mut mismatches = Vec::new();
+
+    let fixtures = get_fixtures(Path::new(dir));
+    if fixtures.is_empty() {
+        panic!("No fixtures in {}", dir);
+    }
+    for fixture in fixtures {
+        let block = load_block(&fixture);
+        let output = parse(&block, &params)
+            .unwrap_or_else(|x| panic!("Failed to parse {}: {}", fixture.display(), x));
+        let output = format!("{:#?}\n", output);
+        let golden = fixture.with_extension(GOLDEN_EXTENSION);
+        if update {
+            fs::write(&golden, &output).unwrap_or_else(|x| panic!("Failed to write {}: {}", golden.display(), x));
+            continue;
+        }
+        let expected = fs::read_to_string(&golden).unwrap_or_default();
+        if expected != output {
+            let diff = TextDiff::from_lines(&expected, &output)
+                .unified_diff()
+                .header(&golden.display().to_string(), "actual")
+                .to_string();
+            mismatches.push(diff);
+        }
+    }
+
+    if !mismatches.is_empty() {
+        panic!(
+            "{} golden(s) differ, rerun with UPDATE_GOLDENS=1 to accept the changes:\n{}",
+            mismatches.len(),
+            mismatches.join("\n"),
+        );
+    }
+}
+
+/// Returns the fixtures in `dir`, sorted by name.
+pub fn get_fixtures(dir: &Path) -> Vec<PathBuf> {
+    let mut fixtures: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|x| x.ok().map(|x| x.path()))
+            .filter(|x| x.extension().map_or(false, |x| x == FIXTURE_EXTENSION))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    fixtures.sort();
+    fixtures
+}
+
+pub fn load_block(path: &Path) -> Block {
+    let data = fs::read(path).unwrap_or_else(|x| panic!("Failed to read {}: {}", path.display(), x));
+    Block::decode(data.as_slice()).unwrap_or_else(|x| panic!("Failed to decode {}: {}", path.display(), x))
+}
@@ -0,0 +1,51 @@
+use std::rc::Rc;
+
+use substreams_solana_utils::instruction::StructuredInstruction;
+
+use crate::pb::solana_common::InstructionPosition;
+
+/// Walks the instruction tree in execution order, pairing every instruction with its position.
+///
+/// `outer_index` is the index of the top-level instruction in the transaction message, and
+/// `inner_index` the index within that instruction's inner instructions, as laid out in the
+/// transaction metadata.
+pub fn get_positioned_instructions<'a>(
+    instructions: &[Rc<StructuredInstruction<'a>>],
+) -> Vec<(InstructionPosition, Rc<StructuredInstruction<'a>>)> {
+    let mut positioned_instructions = Vec::new();
+    for (outer_index, instruction) in instructions.iter().enumerate() {
+        let position = InstructionPosition {
+            outer_index: outer_index as u32,
+            inner_index: None,
+            stack_height: 1,
+            parent_program_id: None,
+        };
+        positioned_instructions.push((position, instruction.clone()));
+
+        let mut inner_index = 0;
+        push_inner_instructions(&mut positioned_instructions, instruction, outer_index as u32, &mut inner_index, 2);
+    }
+    positioned_instructions
+}
+
+fn push_inner_instructions<'a>(
+    positioned_instructions: &mut Vec<(InstructionPosition, Rc<StructuredInstruction<'a>>)>,
+    parent_instruction: &StructuredInstruction<'a>,
+    outer_index: u32,
+    inner_index: &mut u32,
+    stack_height: u32,
+) {
+    let parent_program_id = parent_instruction.program_id().to_string();
+    for instruction in parent_instruction.inner_instructions().iter() {
+        let position = InstructionPosition {
+            outer_index,
+            inner_index: Some(*inner_index),
+            stack_height,
+            parent_program_id: Some(parent_program_id.clone()),
+        };
+        positioned_instructions.push((position, instruction.clone()));
+        *inner_index += 1;
+
+        push_inner_instructions(positioned_instructions, instruction, outer_index, inner_index, stack_height + 1);
+    }
+}
@@ -0,0 +1,10 @@
+pub mod pb;
+pub mod envelope;
+pub mod instruction;
+pub mod transaction_error;
+pub mod params;
+pub mod failure;
+pub mod account;
+pub mod block;
+#[cfg(feature = "fixtures")]
+pub mod fixture;
@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error};
+
+/// Module parameters, passed as `key=value` pairs separated by `&`, e.g.
+/// `include_failed_transactions=true&error_policy=report`.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    /// Include failed transactions, with their decoded error, in the module output.
+    pub include_failed_transactions: bool,
+    /// What to do with instructions that fail to parse.
+    pub error_policy: ErrorPolicy,
+}
+
+/// How a module handles instructions that fail to parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Fail the block.
+    #[default]
+    Strict,
+    /// Drop the instruction and count it in `parse_failure_count`.
+    Skip,
+    /// Drop the instruction, count it and emit a `ParseFailure` record in the block output.
+    Report,
+}
+
+impl FromStr for ErrorPolicy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(ErrorPolicy::Strict),
+            "skip" => Ok(ErrorPolicy::Skip),
+            "report" => Ok(ErrorPolicy::Report),
+            _ => Err(anyhow!("Invalid error policy `{}`, expected one of strict, skip or report", s)),
+        }
+    }
+}
+
+impl Params {
+    pub fn parse(params: &str) -> Result<Self, Error> {
+        let mut parsed = Params::default();
+        for pair in params.split('&').map(str::trim).filter(|x| !x.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, "true"));
+            match key {
+                "include_failed_transactions" => parsed.include_failed_transactions = parse_bool(key, value)?,
+                "error_policy" => parsed.error_policy = value.parse()?,
+                _ => return Err(anyhow!("Unknown parameter `{}`", key)),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool, Error> {
+    match value {
+        "true" | "1" => Ok(true),
+        "false" | "0" => Ok(false),
+        _ => Err(anyhow!("Invalid value `{}` for parameter `{}`", value, key)),
+    }
+}
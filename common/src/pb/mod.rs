@@ -0,0 +1,6 @@
+// @generated
+// @@protoc_insertion_point(attribute:solana_common)
+pub mod solana_common {
+    include!("solana_common.rs");
+    // @@protoc_insertion_point(solana_common)
+}
@@ -0,0 +1,103 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct BlockEnvelope {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(uint64, tag="2")]
+    pub parent_slot: u64,
+    #[prost(string, tag="3")]
+    pub blockhash: ::prost::alloc::string::String,
+    #[prost(int64, optional, tag="4")]
+    pub block_time: ::core::option::Option<i64>,
+    #[prost(uint64, optional, tag="5")]
+    pub block_height: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct TransactionEnvelope {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(int64, optional, tag="2")]
+    pub block_time: ::core::option::Option<i64>,
+    #[prost(uint32, tag="3")]
+    pub transaction_index: u32,
+    #[prost(string, tag="4")]
+    pub fee_payer: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="5")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InstructionPosition {
+    #[prost(uint32, tag="1")]
+    pub outer_index: u32,
+    #[prost(uint32, optional, tag="2")]
+    pub inner_index: ::core::option::Option<u32>,
+    #[prost(uint32, tag="3")]
+    pub stack_height: u32,
+    #[prost(string, optional, tag="4")]
+    pub parent_program_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct TransactionError {
+    /// Variant name of the runtime `TransactionError`, e.g. `InstructionError`.
+    #[prost(string, tag="1")]
+    pub error: ::prost::alloc::string::String,
+    /// Index of the failing top-level instruction.
+    #[prost(uint32, optional, tag="2")]
+    pub instruction_index: ::core::option::Option<u32>,
+    /// Variant name of the `InstructionError`, e.g. `Custom`.
+    #[prost(string, optional, tag="3")]
+    pub instruction_error: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag="4")]
+    pub custom_error_code: ::core::option::Option<u32>,
+    /// Program that raised the error, as reported by the transaction logs.
+    #[prost(string, optional, tag="5")]
+    pub program_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Name of the custom error, when the program's error table is known.
+    #[prost(string, optional, tag="6")]
+    pub custom_error_name: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct ParseFailure {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    /// Unset when the transaction as a whole could not be parsed.
+    #[prost(message, optional, tag="2")]
+    pub position: ::core::option::Option<InstructionPosition>,
+    #[prost(string, tag="3")]
+    pub error: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct TokenAccount {
+    #[prost(string, tag="1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="4")]
+    pub pre_balance: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="5")]
+    pub post_balance: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct AccountBalance {
+    #[prost(uint64, tag="1")]
+    pub pre_balance: u64,
+    #[prost(uint64, tag="2")]
+    pub post_balance: u64,
+}
+// @@protoc_insertion_point(module)
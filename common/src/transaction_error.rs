@@ -0,0 +1,197 @@
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+
+use crate::pb::solana_common::TransactionError;
+
+pub const INSTRUCTION_ERROR_VARIANT: u32 = 8;
+pub const CUSTOM_ERROR_VARIANT: u32 = 25;
+pub const BORSH_IO_ERROR_VARIANT: u32 = 44;
+
+/// Variant names of the runtime `TransactionError`, in bincode discriminant order.
+pub const TRANSACTION_ERRORS: &[&str] = &[
+    "AccountInUse",
+    "AccountLoadedTwice",
+    "AccountNotFound",
+    "ProgramAccountNotFound",
+    "InsufficientFundsForFee",
+    "InvalidAccountForFee",
+    "AlreadyProcessed",
+    "BlockhashNotFound",
+    "InstructionError",
+    "CallChainTooDeep",
+    "MissingSignatureForFee",
+    "InvalidAccountIndex",
+    "SignatureFailure",
+    "InvalidProgramForExecution",
+    "SanitizeFailure",
+    "ClusterMaintenance",
+    "AccountBorrowOutstanding",
+    "WouldExceedMaxBlockCostLimit",
+    "UnsupportedVersion",
+    "InvalidWritableAccount",
+    "WouldExceedMaxAccountCostLimit",
+    "WouldExceedAccountDataBlockLimit",
+    "TooManyAccountLocks",
+    "AddressLookupTableNotFound",
+    "InvalidAddressLookupTableOwner",
+    "InvalidAddressLookupTableData",
+    "InvalidAddressLookupTableIndex",
+    "InvalidRentPayingAccount",
+    "WouldExceedMaxVoteCostLimit",
+    "WouldExceedAccountDataTotalLimit",
+    "DuplicateInstruction",
+    "InsufficientFundsForRent",
+    "MaxLoadedAccountsDataSizeExceeded",
+    "InvalidLoadedAccountsDataSizeLimit",
+    "ResanitizationNeeded",
+    "ProgramExecutionTemporarilyRestricted",
+    "UnbalancedTransaction",
+    "ProgramCacheHitMaxLimit",
+];
+
+/// Variant names of the runtime `InstructionError`, in bincode discriminant order.
+pub const INSTRUCTION_ERRORS: &[&str] = &[
+    "GenericError",
+    "InvalidArgument",
+    "InvalidInstructionData",
+    "InvalidAccountData",
+    "AccountDataTooSmall",
+    "InsufficientFunds",
+    "IncorrectProgramId",
+    "MissingRequiredSignature",
+    "AccountAlreadyInitialized",
+    "UninitializedAccount",
+    "UnbalancedInstruction",
+    "ModifiedProgramId",
+    "ExternalAccountLamportSpend",
+    "ExternalAccountDataModified",
+    "ReadonlyLamportChange",
+    "ReadonlyDataModified",
+    "DuplicateAccountIndex",
+    "ExecutableModified",
+    "RentEpochModified",
+    "NotEnoughAccountKeys",
+    "AccountDataSizeChanged",
+    "AccountNotExecutable",
+    "AccountBorrowFailed",
+    "AccountBorrowOutstanding",
+    "DuplicateAccountOutOfSync",
+    "Custom",
+    "InvalidError",
+    "ExecutableDataModified",
+    "ExecutableLamportChange",
+    "ExecutableAccountNotRentExempt",
+    "UnsupportedProgramId",
+    "CallDepth",
+    "MissingAccount",
+    "ReentrancyNotAllowed",
+    "MaxSeedLengthExceeded",
+    "InvalidSeeds",
+    "InvalidRealloc",
+    "ComputationalBudgetExceeded",
+    "PrivilegeEscalation",
+    "ProgramEnvironmentSetupFailure",
+    "ProgramFailedToComplete",
+    "ProgramFailedToCompile",
+    "Immutable",
+    "IncorrectAuthority",
+    "BorshIoError",
+    "AccountNotRentExempt",
+    "InvalidAccountOwner",
+    "ArithmeticOverflow",
+    "UnsupportedSysvar",
+    "IllegalOwner",
+    "MaxAccountsDataAllocationsExceeded",
+    "MaxAccountsResizeExceeded",
+    "MaxInstructionTraceLengthExceeded",
+    "BuiltinProgramsMustConsumeComputeUnits",
+];
+
+/// Decodes the error of a failed transaction. Returns `None` if the transaction succeeded or
+/// carries no metadata.
+///
+/// The error is stored bincode-serialized in the transaction metadata. Unknown variants, as
+/// introduced by newer runtimes, are reported as `Unknown(<discriminant>)` rather than failing.
+pub fn get_transaction_error(transaction: &ConfirmedTransaction) -> Option<TransactionError> {
+    let meta = transaction.meta.as_ref()?;
+    let data = &meta.err.as_ref()?.err;
+
+    let mut error = TransactionError::default();
+    let variant = match read_u32(data, 0) {
+        Some(variant) => variant,
+        None => {
+            error.error = "Unknown".to_string();
+            return Some(error);
+        }
+    };
+    error.error = variant_name(TRANSACTION_ERRORS, variant);
+
+    if variant == INSTRUCTION_ERROR_VARIANT {
+        let instruction_index = data.get(4).map(|x| *x as u32);
+        error.instruction_index = instruction_index;
+        if let Some(instruction_variant) = read_u32(data, 5) {
+            error.instruction_error = Some(variant_name(INSTRUCTION_ERRORS, instruction_variant));
+            match instruction_variant {
+                CUSTOM_ERROR_VARIANT => error.custom_error_code = read_u32(data, 9),
+                BORSH_IO_ERROR_VARIANT => {
+                    if let Some(message) = read_string(data, 9) {
+                        error.instruction_error = Some(format!("BorshIoError({})", message));
+                    }
+                }
+                _ => (),
+            }
+        }
+        error.program_id = get_failed_program_id(&meta.log_messages)
+            .or_else(|| instruction_index.and_then(|index| get_instruction_program_id(transaction, index as usize)));
+    }
+
+    Some(error)
+}
+
+impl TransactionError {
+    /// Whether the error was raised by `program_id`.
+    pub fn is_raised_by(&self, program_id: &str) -> bool {
+        self.program_id.as_deref() == Some(program_id)
+    }
+
+    /// Fills `custom_error_name` using `name`, if the error is a custom error raised by `program_id`.
+    pub fn name_custom_error<F: Fn(u32) -> Option<String>>(&mut self, program_id: &str, name: F) {
+        if self.is_raised_by(program_id) {
+            self.custom_error_name = self.custom_error_code.and_then(name);
+        }
+    }
+}
+
+/// Returns the program that raised the error. The innermost failing program logs its failure
+/// first; every caller up the stack then logs its own failure after it.
+fn get_failed_program_id(log_messages: &[String]) -> Option<String> {
+    log_messages.iter().find_map(|log| {
+        let rest = log.strip_prefix("Program ")?;
+        let (program_id, rest) = rest.split_once(' ')?;
+        rest.starts_with("failed: ").then(|| program_id.to_string())
+    })
+}
+
+fn get_instruction_program_id(transaction: &ConfirmedTransaction, index: usize) -> Option<String> {
+    let message = transaction.transaction.as_ref()?.message.as_ref()?;
+    let instruction = message.instructions.get(index)?;
+    let program_id = message.account_keys.get(instruction.program_id_index as usize)?;
+    Some(bs58::encode(program_id).into_string())
+}
+
+fn variant_name(names: &[&str], variant: u32) -> String {
+    match names.get(variant as usize) {
+        Some(name) => name.to_string(),
+        None => format!("Unknown({})", variant),
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes(bytes.try_into().ok()?))
+}
+
+fn read_string(data: &[u8], offset: usize) -> Option<String> {
+    let len = u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?) as usize;
+    let bytes = data.get(offset + 8..(offset + 8).checked_add(len)?)?;
+    Some(String::from_utf8_lossy(bytes).to_string())
+}
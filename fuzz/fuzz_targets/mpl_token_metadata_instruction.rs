@@ -0,0 +1,9 @@
+#![no_main]
+
+use borsh::BorshDeserialize;
+use libfuzzer_sys::fuzz_target;
+use mpl_token_metadata_substream::mpl_token_metadata::instruction::MetadataInstruction;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = MetadataInstruction::try_from_slice(data);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pumpfun_substream::pumpfun::instruction::PumpfunInstruction;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = PumpfunInstruction::unpack(data);
+});
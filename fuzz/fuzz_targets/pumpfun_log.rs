@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pumpfun_substream::pumpfun::log::PumpfunLog;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = PumpfunLog::unpack(data);
+});
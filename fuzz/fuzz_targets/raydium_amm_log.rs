@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use raydium_amm_substream::raydium_amm::log::decode_ray_log;
+
+fuzz_target!(|data: &[u8]| {
+    // Ray logs are base64, but arbitrary strings exercise the decoding as well.
+    if let Ok(log) = std::str::from_utf8(data) {
+        let _ = decode_ray_log(log);
+    }
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use substreams_solana_utils::spl_token::TokenInstruction;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = TokenInstruction::unpack(data);
+});
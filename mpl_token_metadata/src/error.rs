@@ -0,0 +1,20 @@
+use num_traits::FromPrimitive;
+use thiserror::Error;
+
+use crate::mpl_token_metadata::error::MetadataError;
+
+/// Names a Token Metadata error.
+pub fn metadata_error_name(code: u32) -> Option<String> {
+    MetadataError::from_u32(code).map(|x| format!("{:?}", x))
+}
+
+/// Errors raised while parsing a Token Metadata instruction.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("Not a Metaplex Token Metadata instruction")]
+    InvalidProgram,
+    #[error("Failed to unpack MetadataInstruction: {0}")]
+    Unpack(String),
+    #[error("Missing account at index {0}")]
+    MissingAccount(usize),
+}
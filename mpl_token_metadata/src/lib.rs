@@ -6,52 +6,102 @@ use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::pb::sf::solana::r#type::v1::Block;
 
 use substreams_solana_utils as utils;
-use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::instruction::{get_structured_instructions, StructuredInstruction};
 use utils::transaction::{get_context, TransactionContext};
 
+use solana_substreams_common::block;
+use solana_substreams_common::envelope::get_block_envelope;
+use solana_substreams_common::instruction::get_positioned_instructions;
+use solana_substreams_common::failure::ParseFailures;
+use solana_substreams_common::params::Params;
+use solana_substreams_common::pb::solana_common::TransactionError;
+use solana_substreams_common::transaction_error::get_transaction_error;
+
 pub mod mpl_token_metadata;
 use mpl_token_metadata::constants::MPL_TOKEN_METADATA_PROGRAM_ID;
 use mpl_token_metadata::instruction::MetadataInstruction;
 
+pub mod error;
+use error::{ParseError, metadata_error_name};
+
 pub mod pb;
 use pb::mpl_token_metadata::*;
 use pb::mpl_token_metadata::mpl_token_metadata_event::Event;
 
 #[substreams::handlers::map]
-fn mpl_token_metadata_events(block: Block) -> Result<MplTokenMetadataBlockEvents, Error> {
-    let transactions = parse_block(&block);
-    Ok(MplTokenMetadataBlockEvents { transactions })
+fn mpl_token_metadata_events(params: String, block: Block) -> Result<MplTokenMetadataBlockEvents, Error> {
+    get_block_events(&block, &Params::parse(&params)?)
 }
 
-pub fn parse_block(block: &Block) -> Vec<MplTokenMetadataTransactionEvents> {
-    let mut block_events: Vec<MplTokenMetadataTransactionEvents> = Vec::new();
+pub fn get_block_events(block: &Block, params: &Params) -> Result<MplTokenMetadataBlockEvents, Error> {
+    let mut failures = ParseFailures::new(params.error_policy);
+    let transactions = parse_block(block, params, &mut failures)?;
+    Ok(MplTokenMetadataBlockEvents {
+        transactions,
+        envelope: Some(get_block_envelope(block)),
+        parse_failure_count: failures.count(),
+        parse_failures: failures.into_failures(),
+    })
+}
 
-    for transaction in block.transactions() {
-        if let Ok(events) = parse_transaction(transaction) {
-            if !events.is_empty() {
-                block_events.push(MplTokenMetadataTransactionEvents {
-                    signature: utils::transaction::get_signature(&transaction),
-                    events,
-                });
-            }
-        }
+pub fn parse_block(block: &Block, params: &Params, failures: &mut ParseFailures) -> Result<Vec<MplTokenMetadataTransactionEvents>, Error> {
+    let transactions = block::parse_block(
+        block,
+        params,
+        failures,
+        &[MPL_TOKEN_METADATA_PROGRAM_ID.to_string()],
+        get_mpl_token_metadata_transaction_error,
+        _parse_transaction,
+    )?;
+    Ok(transactions.into_iter().map(|x| MplTokenMetadataTransactionEvents {
+        signature: x.signature,
+        events: x.events,
+        envelope: Some(x.envelope),
+        error: x.error,
+    }).collect())
+}
+
+/// Decodes the transaction error, naming it when raised by the Token Metadata program.
+pub fn get_mpl_token_metadata_transaction_error(transaction: &ConfirmedTransaction) -> Option<TransactionError> {
+    let mut error = get_transaction_error(transaction)?;
+    error.name_custom_error(&MPL_TOKEN_METADATA_PROGRAM_ID.to_string(), metadata_error_name);
+    Some(error)
+}
+
+/// Parses a successful transaction, handing instructions that fail to parse to `failures`.
+pub fn parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<MplTokenMetadataEvent>, Error> {
+    if transaction.meta.as_ref().map_or(true, |meta| meta.err.is_some()) {
+        return Ok(Vec::new());
     }
-    block_events
+    _parse_transaction(transaction, failures)
+}
+
+/// Parses the instructions of a failed transaction up to the point of failure. Instructions
+/// that cannot be parsed are handed to `failures`, but don't fail the block under the `strict`
+/// policy.
+pub fn parse_failed_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<MplTokenMetadataEvent>, Error> {
+    failures.without_failing(|failures| _parse_transaction(transaction, failures))
 }
 
-pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<MplTokenMetadataEvent>, String> {
+fn _parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<MplTokenMetadataEvent>, Error> {
     let mut events: Vec<MplTokenMetadataEvent> = Vec::new();
 
-    let context = get_context(transaction).unwrap();
-    let instructions = get_structured_instructions(transaction).unwrap();
+    let context = match get_context(transaction) {
+        Ok(context) => context,
+        Err(error) => return failures.record(&utils::transaction::get_signature(transaction), None, error).map(|_| events),
+    };
+    let instructions = match get_structured_instructions(transaction) {
+        Ok(instructions) => instructions,
+        Err(error) => return failures.record(&context.signature, None, error).map(|_| events),
+    };
 
-    for instruction in instructions.flattened().iter() {
+    for (position, instruction) in get_positioned_instructions(&instructions) {
         if instruction.program_id() != MPL_TOKEN_METADATA_PROGRAM_ID {
             continue;
         }
-        match parse_instruction(instruction, &context) {
-            Ok(event) => events.push(MplTokenMetadataEvent { event } ),
-            _ => (),
+        match parse_instruction(&instruction, &context) {
+            Ok(event) => events.push(MplTokenMetadataEvent { event, position: Some(position) } ),
+            Err(error) => failures.record(&context.signature, Some(position), error)?,
         }
     }
     Ok(events)
@@ -60,11 +110,11 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<MplTo
 pub fn parse_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext
-) -> Result<Option<Event>, String> {
+) -> Result<Option<Event>, ParseError> {
     if instruction.program_id() != MPL_TOKEN_METADATA_PROGRAM_ID {
-        return Err("Not a Metaplex Token Metadata instruction.".into());
+        return Err(ParseError::InvalidProgram);
     }
-    let unpacked = MetadataInstruction::try_from_slice(instruction.data()).map_err(|_| "Failed to parse MetadataInstruction.")?;
+    let unpacked = MetadataInstruction::try_from_slice(instruction.data()).map_err(|x| ParseError::Unpack(x.to_string()))?;
     match unpacked {
         MetadataInstruction::ApproveCollectionAuthority => {
             Ok(Some(Event::ApproveCollectionAuthority(ApproveCollectionAuthorityEvent {})))
@@ -250,10 +300,10 @@ fn _parse_create_metadata_account_v3_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     _context: &TransactionContext,
     create_metadata_account_v3: mpl_token_metadata::instruction::CreateMetadataAccountArgsV3,
-) -> Result<CreateMetadataAccountV3Event, String> {
-    let metadata = instruction.accounts()[0].to_string();
-    let mint = instruction.accounts()[1].to_string();
-    let update_authority = instruction.accounts()[4].to_string();
+) -> Result<CreateMetadataAccountV3Event, ParseError> {
+    let metadata = get_account(instruction, 0)?;
+    let mint = get_account(instruction, 1)?;
+    let update_authority = get_account(instruction, 4)?;
     let data = Some(create_metadata_account_v3.data.into());
     let is_mutable = create_metadata_account_v3.is_mutable;
     let collection_details = create_metadata_account_v3.collection_details.map(|x| x.into());
@@ -268,6 +318,10 @@ fn _parse_create_metadata_account_v3_instruction<'a>(
     })
 }
 
+fn get_account(instruction: &StructuredInstruction, index: usize) -> Result<String, ParseError> {
+    instruction.accounts().get(index).map(|x| x.to_string()).ok_or(ParseError::MissingAccount(index))
+}
+
 // fn _parse_create_instruction<'a>(
 //     instruction: &StructuredInstruction<'a>,
 //     _context: &TransactionContext,
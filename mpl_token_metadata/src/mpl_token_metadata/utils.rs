@@ -7,11 +7,11 @@ pub fn try_from_slice_checked<T: TokenMetadataAccount>(
     data_size: usize,
 ) -> Result<T, ProgramError> {
     if !T::is_correct_account_type(data, data_type, data_size) {
-        panic!();
+        return Err(ProgramError::InvalidAccountData);
     }
 
     let mut data_mut = data;
-    let result = T::deserialize(&mut data_mut).unwrap();
+    let result = T::deserialize(&mut data_mut).map_err(|x| ProgramError::BorshIoError(x.to_string()))?;
 
     Ok(result)
 }
@@ -1,21 +1,38 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MplTokenMetadataBlockEvents {
     #[prost(message, repeated, tag="1")]
     pub transactions: ::prost::alloc::vec::Vec<MplTokenMetadataTransactionEvents>,
+    #[prost(message, optional, tag="2")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::BlockEnvelope>,
+    /// Instructions that failed to parse. Only populated with `error_policy=report`.
+    #[prost(message, repeated, tag="3")]
+    pub parse_failures: ::prost::alloc::vec::Vec<::solana_substreams_common::pb::solana_common::ParseFailure>,
+    #[prost(uint32, tag="4")]
+    pub parse_failure_count: u32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MplTokenMetadataTransactionEvents {
     #[prost(string, tag="1")]
     pub signature: ::prost::alloc::string::String,
     #[prost(message, repeated, tag="2")]
     pub events: ::prost::alloc::vec::Vec<MplTokenMetadataEvent>,
+    #[prost(message, optional, tag="3")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionEnvelope>,
+    /// Set on failed transactions, which are only included when `include_failed_transactions` is enabled.
+    #[prost(message, optional, tag="4")]
+    pub error: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionError>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MplTokenMetadataEvent {
+    #[prost(message, optional, tag="59")]
+    pub position: ::core::option::Option<::solana_substreams_common::pb::solana_common::InstructionPosition>,
     #[prost(oneof="mpl_token_metadata_event::Event", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58")]
     pub event: ::core::option::Option<mpl_token_metadata_event::Event>,
 }
@@ -23,6 +40,7 @@ pub struct MplTokenMetadataEvent {
 pub mod mpl_token_metadata_event {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
     pub enum Event {
         #[prost(message, tag="1")]
         CreateMetadataAccountV3(super::CreateMetadataAccountV3Event),
@@ -144,222 +162,277 @@ pub mod mpl_token_metadata_event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct ApproveCollectionAuthorityEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct ApproveUseAuthorityEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct BubblegumSetCollectionSizeEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct BurnEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct BurnEditionNftEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct BurnNftEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CloseEscrowAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct ConvertMasterEditionV1ToV2Event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateEscrowAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateMasterEditionEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateMasterEditionV3Event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateMetadataAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateMetadataAccountV2Event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct DelegateEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct DeprecatedCreateMasterEditionEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct DeprecatedCreateReservationListEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct DeprecatedMintPrintingTokensEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct DeprecatedMintPrintingTokensViaTokenEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct DeprecatedSetReservationListEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct FreezeDelegatedAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct LockEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MigrateEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MintNewEditionFromMasterEditionViaTokenEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MintNewEditionFromMasterEditionViaVaultProxyEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct PuffMetadataEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct RemoveCreatorVerificationEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct RevokeEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct RevokeCollectionAuthorityEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct RevokeUseAuthorityEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SetAndVerifyCollectionEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SetAndVerifySizedCollectionItemEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SetTokenStandardEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SignMetadataEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct ThawDelegatedAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct TransferEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct TransferOutOfEscrowEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UnlockEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UnverifyEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UnverifyCollectionEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UnverifySizedCollectionItemEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateMetadataAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdatePrimarySaleHappenedViaTokenEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UtilizeEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct PrintEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct VerifyEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MintEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SetCollectionSizeEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CollectEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UseEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct VerifySizedCollectionItemEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct VerifyCollectionEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct ResizeEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CloseAccountsEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateMetadataAccountV3Event {
     #[prost(string, tag="1")]
     pub metadata: ::prost::alloc::string::String,
@@ -376,14 +449,17 @@ pub struct CreateMetadataAccountV3Event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateMetadataAccountV2Event {
 }
 // message CreateEvent {
@@ -422,6 +498,7 @@ pub struct UpdateMetadataAccountV2Event {
 
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateArgsV1 {
     #[prost(string, optional, tag="1")]
     pub new_update_authority: ::core::option::Option<::prost::alloc::string::String>,
@@ -444,6 +521,7 @@ pub struct UpdateArgsV1 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateArgsAsUpdateAuthorityV2 {
     #[prost(string, optional, tag="1")]
     pub new_update_authority: ::core::option::Option<::prost::alloc::string::String>,
@@ -468,6 +546,7 @@ pub struct UpdateArgsAsUpdateAuthorityV2 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateArgsAsAuthorityItemDelegateV2 {
     #[prost(string, optional, tag="1")]
     pub new_update_authority: ::core::option::Option<::prost::alloc::string::String>,
@@ -482,6 +561,7 @@ pub struct UpdateArgsAsAuthorityItemDelegateV2 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateArgsAsCollectionDelegateV2 {
     #[prost(message, optional, tag="1")]
     pub collection: ::core::option::Option<CollectionToggle>,
@@ -490,6 +570,7 @@ pub struct UpdateArgsAsCollectionDelegateV2 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateArgsAsDataDelegateV2 {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<Data>,
@@ -498,6 +579,7 @@ pub struct UpdateArgsAsDataDelegateV2 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateArgsAsProgrammableConfigDelegateV2 {
     #[prost(message, optional, tag="1")]
     pub rule_set: ::core::option::Option<RuleSetToggle>,
@@ -506,6 +588,7 @@ pub struct UpdateArgsAsProgrammableConfigDelegateV2 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateArgsAsDataItemDelegateV2 {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<Data>,
@@ -514,6 +597,7 @@ pub struct UpdateArgsAsDataItemDelegateV2 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateArgsAsCollectionItemDelegateV2 {
     #[prost(message, optional, tag="1")]
     pub collection: ::core::option::Option<CollectionToggle>,
@@ -522,6 +606,7 @@ pub struct UpdateArgsAsCollectionItemDelegateV2 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpdateArgsAsProgrammableConfigItemDelegateV2 {
     #[prost(message, optional, tag="1")]
     pub rule_set: ::core::option::Option<RuleSetToggle>,
@@ -530,6 +615,7 @@ pub struct UpdateArgsAsProgrammableConfigItemDelegateV2 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Data {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
@@ -544,10 +630,12 @@ pub struct Data {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct PrintSupply {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct AssetData {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
@@ -574,30 +662,37 @@ pub struct AssetData {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct RuleSetToggle {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CollectionToggle {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct AuthorizationData {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CollectionDetailsToggle {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UsesToggle {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct TokenStandard {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct DataV2 {
     #[prost(string, tag="1")]
     pub name: ::prost::alloc::string::String,
@@ -616,6 +711,7 @@ pub struct DataV2 {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Uses {
     #[prost(enumeration="UseMethod", tag="1")]
     pub use_method: i32,
@@ -626,6 +722,7 @@ pub struct Uses {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Collection {
     #[prost(bool, tag="1")]
     pub verified: bool,
@@ -634,6 +731,7 @@ pub struct Collection {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CollectionDetails {
     #[prost(oneof="collection_details::Version", tags="1, 2")]
     pub version: ::core::option::Option<collection_details::Version>,
@@ -642,6 +740,7 @@ pub struct CollectionDetails {
 pub mod collection_details {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
     pub enum Version {
         #[prost(message, tag="1")]
         V1(super::CollectionDetailsV1),
@@ -651,18 +750,21 @@ pub mod collection_details {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CollectionDetailsV1 {
     #[prost(uint64, tag="1")]
     pub size: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CollectionDetailsV2 {
     #[prost(uint64, repeated, tag="1")]
     pub padding: ::prost::alloc::vec::Vec<u64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Creator {
     #[prost(string, tag="1")]
     pub address: ::prost::alloc::string::String,
@@ -672,6 +774,7 @@ pub struct Creator {
     pub share: u32,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 #[repr(i32)]
 pub enum UseMethod {
     Null = 0,
@@ -704,6 +807,7 @@ impl UseMethod {
     }
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 #[repr(i32)]
 pub enum Toggle {
     Null = 0,
@@ -0,0 +1,16 @@
+use borsh::BorshDeserialize;
+use proptest::prelude::*;
+
+use mpl_token_metadata_substream::mpl_token_metadata::instruction::MetadataInstruction;
+
+proptest! {
+    #[test]
+    fn unpack_instruction_is_total(data in prop::collection::vec(any::<u8>(), 0..512)) {
+        let _ = MetadataInstruction::try_from_slice(&data);
+    }
+
+    #[test]
+    fn unpack_tagged_instruction_is_total(tag in 0u8..64, data in prop::collection::vec(any::<u8>(), 0..512)) {
+        let _ = MetadataInstruction::try_from_slice(&[&[tag], data.as_slice()].concat());
+    }
+}
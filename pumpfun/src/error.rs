@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// Errors raised while parsing a Pumpfun instruction.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("Not a Pumpfun instruction")]
+    InvalidProgram,
+    #[error("Failed to unpack Pumpfun instruction: {0}")]
+    Unpack(&'static str),
+    #[error("Missing account at index {0}")]
+    MissingAccount(usize),
+    #[error("Failed to parse logs due to truncation")]
+    TruncatedLogs,
+    #[error("Failed to decode Pumpfun event: {0}")]
+    InvalidLog(&'static str),
+    #[error("Pumpfun event log not found")]
+    MissingLog,
+    #[error("Unexpected inner instruction layout: {0}")]
+    UnexpectedInnerInstructions(String),
+    #[error(transparent)]
+    SplToken(#[from] spl_token_substream::error::ParseError),
+    #[error(transparent)]
+    SystemProgram(#[from] system_program_substream::error::ParseError),
+}
@@ -1,20 +1,26 @@
-use anyhow::Context;
-use anyhow::{anyhow, Error};
+use std::rc::Rc;
+
+use anyhow::Error;
 
 use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::pb::sf::solana::r#type::v1::Block;
 
-use substreams_solana_utils::spl_token::TOKEN_PROGRAM_ID;
+use spl_token_substream::TOKEN_PROGRAM_IDS;
 use substreams_solana_utils as utils;
-use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::instruction::{get_structured_instructions, StructuredInstruction};
 use utils::system_program::SYSTEM_PROGRAM_ID;
 use utils::transaction::{get_context, TransactionContext};
 use utils::log::Log;
+use utils::pubkey::Pubkey;
 
 pub mod pumpfun;
 use pumpfun::PUMPFUN_PROGRAM_ID;
 use pumpfun::log::PumpfunLog;
 use pumpfun::instruction::PumpfunInstruction;
+use pumpfun::error::PumpfunError;
+
+pub mod error;
+use error::ParseError;
 
 pub mod pb;
 use pb::pumpfun::*;
@@ -22,37 +28,75 @@ use pb::pumpfun::pumpfun_event::Event;
 
 use system_program_substream;
 
+use solana_substreams_common::block;
+use solana_substreams_common::envelope::get_block_envelope;
+use solana_substreams_common::instruction::get_positioned_instructions;
+use solana_substreams_common::failure::ParseFailures;
+use solana_substreams_common::params::Params;
+use solana_substreams_common::pb::solana_common::{TokenAccount, TransactionError};
+use solana_substreams_common::transaction_error::get_transaction_error;
+
 #[substreams::handlers::map]
-fn pumpfun_events(block: Block) -> Result<PumpfunBlockEvents, Error> {
-    let transactions = parse_block(&block)?;
-    Ok(PumpfunBlockEvents { transactions })
+fn pumpfun_events(params: String, block: Block) -> Result<PumpfunBlockEvents, Error> {
+    get_block_events(&block, &Params::parse(&params)?)
 }
 
-pub fn parse_block(block: &Block) -> Result<Vec<PumpfunTransactionEvents>, Error> {
-    let mut block_events: Vec<PumpfunTransactionEvents> = Vec::new();
-    for transaction in block.transactions() {
-        let events = parse_transaction(transaction)?;
-        if !events.is_empty() {
-            block_events.push(PumpfunTransactionEvents {
-                signature: utils::transaction::get_signature(&transaction),
-                events,
-            });
-        }
-    }
-    Ok(block_events)
+pub fn get_block_events(block: &Block, params: &Params) -> Result<PumpfunBlockEvents, Error> {
+    let mut failures = ParseFailures::new(params.error_policy);
+    let transactions = parse_block(block, params, &mut failures)?;
+    Ok(PumpfunBlockEvents {
+        transactions,
+        envelope: Some(get_block_envelope(block)),
+        parse_failure_count: failures.count(),
+        parse_failures: failures.into_failures(),
+    })
 }
 
-pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<PumpfunEvent>, Error> {
-    if let Some(_) = transaction.meta.as_ref().unwrap().err {
+pub fn parse_block(block: &Block, params: &Params, failures: &mut ParseFailures) -> Result<Vec<PumpfunTransactionEvents>, Error> {
+    let transactions = block::parse_block(
+        block,
+        params,
+        failures,
+        &[PUMPFUN_PROGRAM_ID.to_string()],
+        get_pumpfun_transaction_error,
+        _parse_transaction,
+    )?;
+    Ok(transactions.into_iter().map(|x| PumpfunTransactionEvents {
+        signature: x.signature,
+        events: x.events,
+        envelope: Some(x.envelope),
+        error: x.error,
+    }).collect())
+}
+
+/// Parses a successful transaction, handing instructions that fail to parse to `failures`.
+pub fn parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<PumpfunEvent>, Error> {
+    if transaction.meta.as_ref().map_or(true, |meta| meta.err.is_some()) {
         return Ok(Vec::new())
     }
+    _parse_transaction(transaction, failures)
+}
+
+/// Parses the instructions of a failed transaction up to the point of failure. Instructions
+/// that cannot be parsed, typically swaps whose transfers never executed, are handed to
+/// `failures`, but don't fail the block under the `strict` policy.
+pub fn parse_failed_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<PumpfunEvent>, Error> {
+    failures.without_failing(|failures| _parse_transaction(transaction, failures))
+}
 
+fn _parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<PumpfunEvent>, Error> {
     let mut events: Vec<PumpfunEvent> = Vec::new();
 
-    let context = get_context(transaction).unwrap();
-    let instructions = get_structured_instructions(transaction).unwrap();
+    let context = match get_context(transaction) {
+        Ok(context) => context,
+        Err(error) => return failures.record(&utils::transaction::get_signature(transaction), None, error).map(|_| events),
+    };
+    let instructions = match get_structured_instructions(transaction) {
+        Ok(instructions) => instructions,
+        Err(error) => return failures.record(&context.signature, None, error).map(|_| events),
+    };
 
-    for instruction in instructions.flattened().iter() {
+    for (position, instruction) in get_positioned_instructions(&instructions) {
         if instruction.program_id() != PUMPFUN_PROGRAM_ID {
             continue;
         }
@@ -61,23 +105,31 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Pumpf
             Ok(Some(event)) => {
                 events.push(PumpfunEvent {
                     event: Some(event),
+                    position: Some(position),
                 })
             }
             Ok(None) => (),
-            Err(error) => return Err(anyhow!("Transaction {} error: {}", &context.signature, error)),
+            Err(error) => failures.record(&context.signature, Some(position), error)?,
         }
     }
     Ok(events)
 }
 
+/// Decodes the transaction error, naming it when raised by the Pumpfun program.
+pub fn get_pumpfun_transaction_error(transaction: &ConfirmedTransaction) -> Option<TransactionError> {
+    let mut error = get_transaction_error(transaction)?;
+    error.name_custom_error(&PUMPFUN_PROGRAM_ID.to_string(), PumpfunError::name);
+    Some(error)
+}
+
 pub fn parse_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext
-) -> Result<Option<Event>, Error> {
+) -> Result<Option<Event>, ParseError> {
     if instruction.program_id() != PUMPFUN_PROGRAM_ID {
-        return Err(anyhow!("Not a Pumpfun instruction."));
+        return Err(ParseError::InvalidProgram);
     }
-    let unpacked = PumpfunInstruction::unpack(instruction.data()).map_err(|x| anyhow!(x))?;
+    let unpacked = PumpfunInstruction::unpack(instruction.data()).map_err(ParseError::Unpack)?;
     match unpacked {
         PumpfunInstruction::Initialize => {
             Ok(Some(Event::Initialize(_parse_initialize_instruction(instruction, context)?)))
@@ -104,8 +156,8 @@ pub fn parse_instruction(
 fn _parse_initialize_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-) -> Result<InitializeEvent, Error> {
-    let user = instruction.accounts()[0].to_string();
+) -> Result<InitializeEvent, ParseError> {
+    let user = get_account(instruction, 0)?;
 
     Ok(InitializeEvent {
         user,
@@ -116,8 +168,8 @@ fn _parse_set_params_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     set_params: pumpfun::instruction::SetParamsInstruction,
-) -> Result<SetParamsEvent, Error> {
-    let user = instruction.accounts()[0].to_string();
+) -> Result<SetParamsEvent, ParseError> {
+    let user = get_account(instruction, 0)?;
     let fee_recipient = set_params.fee_recipient.to_string();
     let initial_virtual_token_reserves = set_params.initial_virtual_token_reserves;
     let initial_virtual_sol_reserves = set_params.initial_virtual_sol_reserves;
@@ -140,15 +192,15 @@ fn _parse_create_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     create: pumpfun::instruction::CreateInstruction,
-) -> Result<CreateEvent, Error> {
-    let user = instruction.accounts()[7].to_string();
+) -> Result<CreateEvent, ParseError> {
+    let user = get_account(instruction, 7)?;
     let name = create.name;
     let symbol = create.symbol;
     let uri = create.uri;
-    let mint = instruction.accounts()[0].to_string();
-    let bonding_curve = instruction.accounts()[2].to_string();
-    let associated_bonding_curve = instruction.accounts()[2].to_string();
-    let metadata = instruction.accounts()[6].to_string();
+    let mint = get_account(instruction, 0)?;
+    let bonding_curve = get_account(instruction, 2)?;
+    let associated_bonding_curve = get_account(instruction, 2)?;
+    let metadata = get_account(instruction, 6)?;
 
     Ok(CreateEvent {
         user,
@@ -166,19 +218,19 @@ fn _parse_buy_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
     buy: pumpfun::instruction::BuyInstruction,
-) -> Result<SwapEvent, Error> {
-    let mint = instruction.accounts()[2].to_string();
-    let bonding_curve = instruction.accounts()[3].to_string();
-    let user = instruction.accounts()[6].to_string();
+) -> Result<SwapEvent, ParseError> {
+    let mint = get_account(instruction, 2)?;
+    let bonding_curve = get_account(instruction, 3)?;
+    let user = get_account(instruction, 6)?;
     let token_amount = buy.amount;
 
-    let system_transfer_instruction = instruction.inner_instructions().iter().find(|x| x.program_id() == SYSTEM_PROGRAM_ID).unwrap().clone();
+    let system_transfer_instruction = find_inner_instruction(instruction, &[SYSTEM_PROGRAM_ID], "System")?;
     let system_transfer = system_program_substream::parse_transfer_instruction(system_transfer_instruction.as_ref(), context)?;
     let sol_amount = Some(system_transfer.lamports);
 
-    let token_transfer_instruction = instruction.inner_instructions().iter().find(|x| x.program_id() == TOKEN_PROGRAM_ID).unwrap().clone();
-    let token_transfer = spl_token_substream::parse_transfer_instruction(token_transfer_instruction.as_ref(), context).map_err(|e| anyhow!(e))?;
-    let user_token_pre_balance = token_transfer.destination.unwrap().pre_balance;
+    let token_transfer_instruction = find_inner_instruction(instruction, &TOKEN_PROGRAM_IDS, "Token")?;
+    let token_transfer = spl_token_substream::parse_transfer_instruction(token_transfer_instruction.as_ref(), context)?;
+    let user_token_pre_balance = unwrap_token_account(token_transfer.destination)?.pre_balance;
 
     let trade = match parse_pumpfun_log(instruction) {
         Ok(PumpfunLog::Trade(trade)) => Some(trade),
@@ -210,10 +262,10 @@ fn _parse_sell_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
     sell: pumpfun::instruction::SellInstruction,
-) -> Result<SwapEvent, Error> {
-    let mint = instruction.accounts()[2].to_string();
-    let user = instruction.accounts()[6].to_string();
-    let bonding_curve = instruction.accounts()[3].to_string();
+) -> Result<SwapEvent, ParseError> {
+    let mint = get_account(instruction, 2)?;
+    let user = get_account(instruction, 6)?;
+    let bonding_curve = get_account(instruction, 3)?;
     let token_amount = sell.amount;
 
     let trade = match parse_pumpfun_log(instruction) {
@@ -228,9 +280,9 @@ fn _parse_sell_instruction(
 
     let direction = "sol".to_string();
 
-    let token_transfer_instruction = instruction.inner_instructions().iter().find(|x| x.program_id() == TOKEN_PROGRAM_ID).unwrap().clone();
-    let token_transfer = spl_token_substream::parse_transfer_instruction(token_transfer_instruction.as_ref(), context).map_err(|e| anyhow!(e))?;
-    let user_token_pre_balance = token_transfer.source.unwrap().pre_balance;
+    let token_transfer_instruction = find_inner_instruction(instruction, &TOKEN_PROGRAM_IDS, "Token")?;
+    let token_transfer = spl_token_substream::parse_transfer_instruction(token_transfer_instruction.as_ref(), context)?;
+    let user_token_pre_balance = unwrap_token_account(token_transfer.source)?.pre_balance;
 
     Ok(SwapEvent {
         user,
@@ -250,18 +302,35 @@ fn _parse_sell_instruction(
 fn _parse_withdraw_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-) -> Result<WithdrawEvent, Error> {
-    let mint = instruction.accounts()[2].to_string();
+) -> Result<WithdrawEvent, ParseError> {
+    let mint = get_account(instruction, 2)?;
 
     Ok(WithdrawEvent {
         mint,
     })
 }
 
-fn parse_pumpfun_log(instruction: &StructuredInstruction) -> Result<PumpfunLog, Error> {
-    let data = instruction.logs().as_ref().context("Failed to parse logs due to truncation")?.iter().find_map(|log| match log {
+fn parse_pumpfun_log(instruction: &StructuredInstruction) -> Result<PumpfunLog, ParseError> {
+    let data = instruction.logs().as_ref().ok_or(ParseError::TruncatedLogs)?.iter().find_map(|log| match log {
         Log::Data(data_log) => data_log.data().ok(),
         _ => None,
-    }).ok_or(anyhow!("Couldn't find data log."))?;
-    PumpfunLog::unpack(data.as_slice()).map_err(|x| anyhow!(x))
+    }).ok_or(ParseError::MissingLog)?;
+    PumpfunLog::unpack(data.as_slice()).map_err(ParseError::InvalidLog)
+}
+
+fn get_account(instruction: &StructuredInstruction, index: usize) -> Result<String, ParseError> {
+    instruction.accounts().get(index).map(|x| x.to_string()).ok_or(ParseError::MissingAccount(index))
+}
+
+fn find_inner_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    program_ids: &[Pubkey],
+    program_name: &str,
+) -> Result<Rc<StructuredInstruction<'a>>, ParseError> {
+    instruction.inner_instructions().iter().find(|x| program_ids.iter().any(|id| x.program_id() == *id)).cloned()
+        .ok_or_else(|| ParseError::UnexpectedInnerInstructions(format!("no {} program inner instruction", program_name)))
+}
+
+fn unwrap_token_account(account: Option<TokenAccount>) -> Result<TokenAccount, ParseError> {
+    account.ok_or_else(|| ParseError::UnexpectedInnerInstructions("Token event without token account".to_string()))
 }
@@ -1,21 +1,38 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct PumpfunBlockEvents {
     #[prost(message, repeated, tag="1")]
     pub transactions: ::prost::alloc::vec::Vec<PumpfunTransactionEvents>,
+    #[prost(message, optional, tag="2")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::BlockEnvelope>,
+    /// Instructions that failed to parse. Only populated with `error_policy=report`.
+    #[prost(message, repeated, tag="3")]
+    pub parse_failures: ::prost::alloc::vec::Vec<::solana_substreams_common::pb::solana_common::ParseFailure>,
+    #[prost(uint32, tag="4")]
+    pub parse_failure_count: u32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct PumpfunTransactionEvents {
     #[prost(string, tag="1")]
     pub signature: ::prost::alloc::string::String,
     #[prost(message, repeated, tag="2")]
     pub events: ::prost::alloc::vec::Vec<PumpfunEvent>,
+    #[prost(message, optional, tag="3")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionEnvelope>,
+    /// Set on failed transactions, which are only included when `include_failed_transactions` is enabled.
+    #[prost(message, optional, tag="4")]
+    pub error: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionError>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct PumpfunEvent {
+    #[prost(message, optional, tag="6")]
+    pub position: ::core::option::Option<::solana_substreams_common::pb::solana_common::InstructionPosition>,
     #[prost(oneof="pumpfun_event::Event", tags="1, 2, 3, 4, 5")]
     pub event: ::core::option::Option<pumpfun_event::Event>,
 }
@@ -23,6 +40,7 @@ pub struct PumpfunEvent {
 pub mod pumpfun_event {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
     pub enum Event {
         #[prost(message, tag="1")]
         Initialize(super::InitializeEvent),
@@ -38,6 +56,7 @@ pub mod pumpfun_event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateEvent {
     #[prost(string, tag="1")]
     pub user: ::prost::alloc::string::String,
@@ -58,12 +77,14 @@ pub struct CreateEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct InitializeEvent {
     #[prost(string, tag="1")]
     pub user: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SetParamsEvent {
     #[prost(string, tag="1")]
     pub user: ::prost::alloc::string::String,
@@ -82,6 +103,7 @@ pub struct SetParamsEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SwapEvent {
     #[prost(string, tag="1")]
     pub user: ::prost::alloc::string::String,
@@ -108,6 +130,7 @@ pub struct SwapEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct WithdrawEvent {
     #[prost(string, tag="1")]
     pub mint: ::prost::alloc::string::String,
@@ -0,0 +1,193 @@
+/// Errors raised by the Pumpfun program, as returned in `InstructionError::Custom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PumpfunError {
+    /// The given account is not authorized to execute this instruction.
+    NotAuthorized = 6000,
+    /// The program is already initialized.
+    AlreadyInitialized = 6001,
+    /// slippage: Too much SOL required to buy the given amount of tokens.
+    TooMuchSolRequired = 6002,
+    /// slippage: Too little SOL received to sell the given amount of tokens.
+    TooLittleSolReceived = 6003,
+    /// The mint does not match the bonding curve.
+    MintDoesNotMatchBondingCurve = 6004,
+    /// The bonding curve has completed and liquidity migrated to raydium.
+    BondingCurveComplete = 6005,
+    /// The bonding curve has not completed.
+    BondingCurveNotComplete = 6006,
+    /// The program is not initialized.
+    NotInitialized = 6007,
+    /// Withdraw too frequent.
+    WithdrawTooFrequent = 6008,
+}
+
+impl PumpfunError {
+    pub fn from_u32(code: u32) -> Option<Self> {
+        match code {
+            6000 => Some(PumpfunError::NotAuthorized),
+            6001 => Some(PumpfunError::AlreadyInitialized),
+            6002 => Some(PumpfunError::TooMuchSolRequired),
+            6003 => Some(PumpfunError::TooLittleSolReceived),
+            6004 => Some(PumpfunError::MintDoesNotMatchBondingCurve),
+            6005 => Some(PumpfunError::BondingCurveComplete),
+            6006 => Some(PumpfunError::BondingCurveNotComplete),
+            6007 => Some(PumpfunError::NotInitialized),
+            6008 => Some(PumpfunError::WithdrawTooFrequent),
+            _ => None,
+        }
+    }
+
+    /// Names a custom error code raised by the Pumpfun program, including Anchor framework errors.
+    pub fn name(code: u32) -> Option<String> {
+        PumpfunError::from_u32(code).map(|x| format!("{:?}", x))
+            .or_else(|| AnchorError::from_u32(code).map(|x| format!("{:?}", x)))
+    }
+}
+
+/// Errors raised by the Anchor framework the Pumpfun program is built on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorError {
+    /// 8 byte instruction identifier not provided
+    InstructionMissing = 100,
+    /// Fallback functions are not supported
+    InstructionFallbackNotFound = 101,
+    /// The program could not deserialize the given instruction
+    InstructionDidNotDeserialize = 102,
+    /// The program could not serialize the given instruction
+    InstructionDidNotSerialize = 103,
+    /// A mut constraint was violated
+    ConstraintMut = 2000,
+    /// A has one constraint was violated
+    ConstraintHasOne = 2001,
+    /// A signer constraint was violated
+    ConstraintSigner = 2002,
+    /// A raw constraint was violated
+    ConstraintRaw = 2003,
+    /// An owner constraint was violated
+    ConstraintOwner = 2004,
+    /// A rent exemption constraint was violated
+    ConstraintRentExempt = 2005,
+    /// A seeds constraint was violated
+    ConstraintSeeds = 2006,
+    /// An executable constraint was violated
+    ConstraintExecutable = 2007,
+    /// Deprecated Error, feel free to replace with something else
+    ConstraintState = 2008,
+    /// An associated constraint was violated
+    ConstraintAssociated = 2009,
+    /// An associated init constraint was violated
+    ConstraintAssociatedInit = 2010,
+    /// A close constraint was violated
+    ConstraintClose = 2011,
+    /// An address constraint was violated
+    ConstraintAddress = 2012,
+    /// Expected zero account discriminant
+    ConstraintZero = 2013,
+    /// A token mint constraint was violated
+    ConstraintTokenMint = 2014,
+    /// A token owner constraint was violated
+    ConstraintTokenOwner = 2015,
+    /// A mint mint authority constraint was violated
+    ConstraintMintMintAuthority = 2016,
+    /// A mint freeze authority constraint was violated
+    ConstraintMintFreezeAuthority = 2017,
+    /// A mint decimals constraint was violated
+    ConstraintMintDecimals = 2018,
+    /// A space constraint was violated
+    ConstraintSpace = 2019,
+    /// A required account for the constraint is None
+    ConstraintAccountIsNone = 2020,
+    /// The account discriminator was already set on this account
+    AccountDiscriminatorAlreadySet = 3000,
+    /// No 8 byte discriminator was found on the account
+    AccountDiscriminatorNotFound = 3001,
+    /// 8 byte discriminator did not match what was expected
+    AccountDiscriminatorMismatch = 3002,
+    /// Failed to deserialize the account
+    AccountDidNotDeserialize = 3003,
+    /// Failed to serialize the account
+    AccountDidNotSerialize = 3004,
+    /// Not enough account keys given to the instruction
+    AccountNotEnoughKeys = 3005,
+    /// The given account is not mutable
+    AccountNotMutable = 3006,
+    /// The given account is owned by a different program than expected
+    AccountOwnedByWrongProgram = 3007,
+    /// Program ID was not as expected
+    InvalidProgramId = 3008,
+    /// Program account is not executable
+    InvalidProgramExecutable = 3009,
+    /// The given account did not sign
+    AccountNotSigner = 3010,
+    /// The given account is not owned by the system program
+    AccountNotSystemOwned = 3011,
+    /// The program expected this account to be already initialized
+    AccountNotInitialized = 3012,
+    /// The given account is not a program data account
+    AccountNotProgramData = 3013,
+    /// The given account is not the associated token account
+    AccountNotAssociatedTokenAccount = 3014,
+    /// The given public key does not match the required sysvar
+    AccountSysvarMismatch = 3015,
+    /// The account reallocation exceeds the MAX_PERMITTED_DATA_INCREASE limit
+    AccountReallocExceedsLimit = 3016,
+    /// The account was duplicated for more than one reallocation
+    AccountDuplicateReallocs = 3017,
+    /// The declared program id does not match the actual program id
+    DeclaredProgramIdMismatch = 4100,
+    /// The API being used is deprecated and should no longer be used
+    Deprecated = 5000,
+}
+
+impl AnchorError {
+    pub fn from_u32(code: u32) -> Option<Self> {
+        match code {
+            100 => Some(AnchorError::InstructionMissing),
+            101 => Some(AnchorError::InstructionFallbackNotFound),
+            102 => Some(AnchorError::InstructionDidNotDeserialize),
+            103 => Some(AnchorError::InstructionDidNotSerialize),
+            2000 => Some(AnchorError::ConstraintMut),
+            2001 => Some(AnchorError::ConstraintHasOne),
+            2002 => Some(AnchorError::ConstraintSigner),
+            2003 => Some(AnchorError::ConstraintRaw),
+            2004 => Some(AnchorError::ConstraintOwner),
+            2005 => Some(AnchorError::ConstraintRentExempt),
+            2006 => Some(AnchorError::ConstraintSeeds),
+            2007 => Some(AnchorError::ConstraintExecutable),
+            2008 => Some(AnchorError::ConstraintState),
+            2009 => Some(AnchorError::ConstraintAssociated),
+            2010 => Some(AnchorError::ConstraintAssociatedInit),
+            2011 => Some(AnchorError::ConstraintClose),
+            2012 => Some(AnchorError::ConstraintAddress),
+            2013 => Some(AnchorError::ConstraintZero),
+            2014 => Some(AnchorError::ConstraintTokenMint),
+            2015 => Some(AnchorError::ConstraintTokenOwner),
+            2016 => Some(AnchorError::ConstraintMintMintAuthority),
+            2017 => Some(AnchorError::ConstraintMintFreezeAuthority),
+            2018 => Some(AnchorError::ConstraintMintDecimals),
+            2019 => Some(AnchorError::ConstraintSpace),
+            2020 => Some(AnchorError::ConstraintAccountIsNone),
+            3000 => Some(AnchorError::AccountDiscriminatorAlreadySet),
+            3001 => Some(AnchorError::AccountDiscriminatorNotFound),
+            3002 => Some(AnchorError::AccountDiscriminatorMismatch),
+            3003 => Some(AnchorError::AccountDidNotDeserialize),
+            3004 => Some(AnchorError::AccountDidNotSerialize),
+            3005 => Some(AnchorError::AccountNotEnoughKeys),
+            3006 => Some(AnchorError::AccountNotMutable),
+            3007 => Some(AnchorError::AccountOwnedByWrongProgram),
+            3008 => Some(AnchorError::InvalidProgramId),
+            3009 => Some(AnchorError::InvalidProgramExecutable),
+            3010 => Some(AnchorError::AccountNotSigner),
+            3011 => Some(AnchorError::AccountNotSystemOwned),
+            3012 => Some(AnchorError::AccountNotInitialized),
+            3013 => Some(AnchorError::AccountNotProgramData),
+            3014 => Some(AnchorError::AccountNotAssociatedTokenAccount),
+            3015 => Some(AnchorError::AccountSysvarMismatch),
+            3016 => Some(AnchorError::AccountReallocExceedsLimit),
+            3017 => Some(AnchorError::AccountDuplicateReallocs),
+            4100 => Some(AnchorError::DeclaredProgramIdMismatch),
+            5000 => Some(AnchorError::Deprecated),
+            _ => None,
+        }
+    }
+}
@@ -1,22 +1,5 @@
-use std::fmt::{self, Display};
 use borsh::BorshDeserialize;
-
-#[derive(BorshDeserialize)]
-pub struct Pubkey(pub [u8; 32]);
-
-impl Display for Pubkey {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", bs58::encode(self.0).into_string())
-    }
-}
-
-impl fmt::Debug for Pubkey {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("Pubkey")
-            .field(&bs58::encode(self.0).into_string())
-            .finish()
-    }
-}
+use substreams_solana_utils::pubkey::Pubkey;
 
 #[derive(Debug)]
 pub enum PumpfunLog {
@@ -28,6 +11,9 @@ pub enum PumpfunLog {
 
 impl PumpfunLog {
     pub fn unpack(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() < 8 {
+            return Err("Event data too short.");
+        }
         let (discriminator, data) = data.split_at(8);
         match discriminator {
             [27, 114, 169, 77, 222, 235, 99, 118] => CreateLog::try_from_slice(data).map(Self::Create).map_err(|_| "Failed to unpack CreateEvent."),
@@ -2,3 +2,4 @@ pub mod instruction;
 pub mod constants;
 pub use constants::PUMPFUN_PROGRAM_ID;
 pub mod log;
+pub mod error;
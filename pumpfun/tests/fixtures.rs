@@ -0,0 +1,8 @@
+use solana_substreams_common::fixture::run_fixtures;
+
+use pumpfun_substream::get_block_events;
+
+#[test]
+fn fixtures() {
+    run_fixtures(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"), get_block_events);
+}
@@ -0,0 +1,48 @@
+use proptest::prelude::*;
+
+use pumpfun_substream::pumpfun::instruction::PumpfunInstruction;
+use pumpfun_substream::pumpfun::log::PumpfunLog;
+
+const INSTRUCTION_DISCRIMINATORS: [[u8; 8]; 6] = [
+    [175, 175, 109, 31, 13, 152, 155, 237],
+    [165, 31, 134, 53, 189, 180, 130, 255],
+    [24, 30, 200, 40, 5, 28, 7, 119],
+    [102, 6, 61, 18, 1, 218, 235, 234],
+    [51, 230, 133, 164, 1, 127, 131, 173],
+    [183, 18, 70, 156, 148, 109, 161, 34],
+];
+
+const LOG_DISCRIMINATORS: [[u8; 8]; 4] = [
+    [27, 114, 169, 77, 222, 235, 99, 118],
+    [189, 219, 127, 211, 78, 230, 97, 238],
+    [95, 114, 97, 156, 212, 46, 152, 8],
+    [223, 195, 159, 246, 62, 48, 143, 131],
+];
+
+proptest! {
+    #[test]
+    fn unpack_instruction_is_total(data in prop::collection::vec(any::<u8>(), 0..256)) {
+        let _ = PumpfunInstruction::unpack(&data);
+    }
+
+    #[test]
+    fn unpack_known_instruction_is_total(
+        discriminator in prop::sample::select(INSTRUCTION_DISCRIMINATORS.to_vec()),
+        data in prop::collection::vec(any::<u8>(), 0..256),
+    ) {
+        let _ = PumpfunInstruction::unpack(&[discriminator.as_slice(), data.as_slice()].concat());
+    }
+
+    #[test]
+    fn unpack_log_is_total(data in prop::collection::vec(any::<u8>(), 0..256)) {
+        let _ = PumpfunLog::unpack(&data);
+    }
+
+    #[test]
+    fn unpack_known_log_is_total(
+        discriminator in prop::sample::select(LOG_DISCRIMINATORS.to_vec()),
+        data in prop::collection::vec(any::<u8>(), 0..256),
+    ) {
+        let _ = PumpfunLog::unpack(&[discriminator.as_slice(), data.as_slice()].concat());
+    }
+}
@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+/// Errors raised while parsing a Raydium AMM instruction.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("Not a Raydium AMM instruction")]
+    InvalidProgram,
+    #[error("Failed to unpack Raydium AMM instruction: {0}")]
+    Unpack(&'static str),
+    #[error("Missing account at index {0}")]
+    MissingAccount(usize),
+    #[error("Unknown token account {0}")]
+    UnknownTokenAccount(String),
+    #[error("Failed to parse logs due to truncation")]
+    TruncatedLogs,
+    #[error("Failed to decode ray log: {0}")]
+    InvalidLog(&'static str),
+    #[error("Ray log not found")]
+    MissingLog,
+    #[error("Unexpected inner instruction layout: {0}")]
+    UnexpectedInnerInstructions(String),
+    #[error(transparent)]
+    SplToken(#[from] spl_token_substream::error::ParseError),
+}
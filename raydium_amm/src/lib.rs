@@ -1,5 +1,7 @@
+use std::rc::Rc;
+
 use regex;
-use anyhow::{anyhow, Error, Context};
+use anyhow::Error;
 
 use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::pb::sf::solana::r#type::v1::Block;
@@ -8,50 +10,91 @@ pub mod raydium_amm;
 use raydium_amm::instruction::AmmInstruction;
 use raydium_amm::constants::RAYDIUM_AMM_PROGRAM_ID;
 use raydium_amm::log::{decode_ray_log, RayLog};
+use raydium_amm::error::AmmError;
 
 use substreams_solana_utils as utils;
-use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::instruction::{get_structured_instructions, StructuredInstruction};
 use utils::transaction::{get_context, TransactionContext};
 use utils::pubkey::Pubkey;
 use utils::log::Log;
 
 use spl_token_substream;
 
+use solana_substreams_common::block;
+use solana_substreams_common::envelope::get_block_envelope;
+use solana_substreams_common::instruction::get_positioned_instructions;
+use solana_substreams_common::failure::ParseFailures;
+use solana_substreams_common::params::Params;
+use solana_substreams_common::pb::solana_common::{TokenAccount, TransactionError};
+use solana_substreams_common::transaction_error::get_transaction_error;
+
+pub mod error;
+use error::ParseError;
+
 pub mod pb;
 use pb::raydium_amm::*;
 use pb::raydium_amm::raydium_amm_event::Event;
 
 #[substreams::handlers::map]
-fn raydium_amm_events(block: Block) -> Result<RaydiumAmmBlockEvents, Error> {
-    let transactions = parse_block(&block);
-    Ok(RaydiumAmmBlockEvents { transactions })
+fn raydium_amm_events(params: String, block: Block) -> Result<RaydiumAmmBlockEvents, Error> {
+    get_block_events(&block, &Params::parse(&params)?)
 }
 
-pub fn parse_block(block: &Block) -> Vec<RaydiumAmmTransactionEvents> {
-    let mut block_events: Vec<RaydiumAmmTransactionEvents> = Vec::new();
-    for transaction in block.transactions.iter() {
-        if let Ok(events) = parse_transaction(transaction) {
-            if !events.is_empty() {
-                block_events.push(RaydiumAmmTransactionEvents {
-                    signature: utils::transaction::get_signature(&transaction),
-                    events,
-                });
-            }
-        }
-    }
-    block_events
+pub fn get_block_events(block: &Block, params: &Params) -> Result<RaydiumAmmBlockEvents, Error> {
+    let mut failures = ParseFailures::new(params.error_policy);
+    let transactions = parse_block(block, params, &mut failures)?;
+    Ok(RaydiumAmmBlockEvents {
+        transactions,
+        envelope: Some(get_block_envelope(block)),
+        parse_failure_count: failures.count(),
+        parse_failures: failures.into_failures(),
+    })
 }
 
-pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<RaydiumAmmEvent>, Error> {
-    if let Some(_) = transaction.meta.as_ref().unwrap().err {
+pub fn parse_block(block: &Block, params: &Params, failures: &mut ParseFailures) -> Result<Vec<RaydiumAmmTransactionEvents>, Error> {
+    let transactions = block::parse_block(
+        block,
+        params,
+        failures,
+        &[RAYDIUM_AMM_PROGRAM_ID.to_string()],
+        get_raydium_amm_transaction_error,
+        _parse_transaction,
+    )?;
+    Ok(transactions.into_iter().map(|x| RaydiumAmmTransactionEvents {
+        signature: x.signature,
+        events: x.events,
+        envelope: Some(x.envelope),
+        error: x.error,
+    }).collect())
+}
+
+/// Parses a successful transaction, handing instructions that fail to parse to `failures`.
+pub fn parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<RaydiumAmmEvent>, Error> {
+    if transaction.meta.as_ref().map_or(true, |meta| meta.err.is_some()) {
         return Ok(Vec::new());
     }
+    _parse_transaction(transaction, failures)
+}
+
+/// Parses the instructions of a failed transaction up to the point of failure. Instructions
+/// that cannot be parsed, typically swaps whose transfers never executed, are handed to
+/// `failures`, but don't fail the block under the `strict` policy.
+pub fn parse_failed_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<RaydiumAmmEvent>, Error> {
+    failures.without_failing(|failures| _parse_transaction(transaction, failures))
+}
 
+fn _parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<RaydiumAmmEvent>, Error> {
     let mut events: Vec<RaydiumAmmEvent> = Vec::new();
 
-    let mut context = get_context(transaction)?;
-    let instructions = get_structured_instructions(transaction)?;
-    for instruction in instructions.flattened().iter() {
+    let mut context = match get_context(transaction) {
+        Ok(context) => context,
+        Err(error) => return failures.record(&utils::transaction::get_signature(transaction), None, error).map(|_| events),
+    };
+    let instructions = match get_structured_instructions(transaction) {
+        Ok(instructions) => instructions,
+        Err(error) => return failures.record(&context.signature, None, error).map(|_| events),
+    };
+    for (position, instruction) in get_positioned_instructions(&instructions) {
         context.update_balance(&instruction.instruction);
         if instruction.program_id() != RAYDIUM_AMM_PROGRAM_ID {
             continue;
@@ -61,23 +104,31 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Raydi
             Ok(Some(event)) => {
                 events.push(RaydiumAmmEvent {
                     event: Some(event),
+                    position: Some(position),
                 })
             }
             Ok(None) => (),
-            Err(error) => substreams::log::println(format!("Failed to process instruction of transaction {}: {}", &context.signature, error))
+            Err(error) => failures.record(&context.signature, Some(position), error)?,
         }
     }
     Ok(events)
 }
 
+/// Decodes the transaction error, naming it when raised by the Raydium AMM program.
+pub fn get_raydium_amm_transaction_error(transaction: &ConfirmedTransaction) -> Option<TransactionError> {
+    let mut error = get_transaction_error(transaction)?;
+    error.name_custom_error(&RAYDIUM_AMM_PROGRAM_ID.to_string(), AmmError::name);
+    Some(error)
+}
+
 pub fn parse_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext
-) -> Result<Option<Event>, String> {
+) -> Result<Option<Event>, ParseError> {
     if instruction.program_id() != RAYDIUM_AMM_PROGRAM_ID {
-        return Err("Instruction does not originate from Raydium AMM Program.".into());
+        return Err(ParseError::InvalidProgram);
     }
-    let unpacked = AmmInstruction::unpack(&instruction.data())?;
+    let unpacked = AmmInstruction::unpack(&instruction.data()).map_err(ParseError::Unpack)?;
     match unpacked {
         AmmInstruction::SwapBaseIn(_) |
         AmmInstruction::SwapBaseOut(_) => {
@@ -107,25 +158,28 @@ pub fn parse_instruction<'a>(
 fn _parse_swap_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<SwapEvent, String> {
-    let amm = instruction.accounts()[1].to_string();
-    let user = instruction.accounts().last().unwrap().to_string();
+) -> Result<SwapEvent, ParseError> {
+    let amm = get_account(instruction, 1)?;
+    let accounts = instruction.accounts();
+    let user = accounts.last().map(|x| x.to_string()).ok_or(ParseError::MissingAccount(accounts.len()))?;
 
-    let instructions_len = instruction.inner_instructions().len();
-    let transfer_in = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
-    let transfer_out = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
+    let transfer_in = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 2)?, context)?;
+    let transfer_out = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 1)?, context)?;
 
     let amount_in = transfer_in.amount;
     let amount_out = transfer_out.amount;
-    let mint_in = transfer_in.source.as_ref().unwrap().mint.clone();
-    let mint_out = transfer_out.source.as_ref().unwrap().mint.clone();
+    let source_in = unwrap_token_account(transfer_in.source)?;
+    let source_out = unwrap_token_account(transfer_out.source)?;
+    let destination_out = unwrap_token_account(transfer_out.destination)?;
+    let mint_in = source_in.mint;
+    let mint_out = source_out.mint;
 
-    let user_pre_balance_in = transfer_in.source.unwrap().pre_balance;
-    let user_pre_balance_out = transfer_out.destination.unwrap().pre_balance;
+    let user_pre_balance_in = source_in.pre_balance;
+    let user_pre_balance_out = destination_out.pre_balance;
 
     let delta = if instruction.accounts().len() == 17 { 0 } else { 1 };
-    let coin_mint = context.get_token_account(&instruction.accounts()[4 + delta]).unwrap().mint.to_string();
-    let pc_mint = context.get_token_account(&instruction.accounts()[5 + delta]).unwrap().mint.to_string();
+    let coin_mint = get_token_account(instruction, context, 4 + delta)?.mint.to_string();
+    let pc_mint = get_token_account(instruction, context, 5 + delta)?.mint.to_string();
 
     let direction = (if mint_out == coin_mint { "coin" } else { "pc" }).to_string();
 
@@ -160,24 +214,25 @@ fn _parse_initialize_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
     nonce: u8,
-) -> Result<InitializeEvent, String> {
-    let amm = instruction.accounts()[4].to_string();
-    let user = instruction.accounts()[17].to_string();
+) -> Result<InitializeEvent, ParseError> {
+    let amm = get_account(instruction, 4)?;
+    let user = get_account(instruction, 17)?;
 
-    let instructions_len = instruction.inner_instructions().len();
-    let coin_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 3], context)?;
-    let pc_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
-    let lp_mint_to = spl_token_substream::parse_mint_to_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
+    let coin_transfer = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 3)?, context)?;
+    let pc_transfer = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 2)?, context)?;
+    let lp_mint_to = spl_token_substream::parse_mint_to_instruction(&get_inner_instruction_from_end(instruction, 1)?, context)?;
 
     let pc_init_amount = pc_transfer.amount;
     let coin_init_amount = coin_transfer.amount;
     let lp_init_amount = lp_mint_to.amount;
-    let pc_mint = pc_transfer.source.as_ref().unwrap().mint.clone();
-    let coin_mint = coin_transfer.source.as_ref().unwrap().mint.clone();
+    let pc_source = unwrap_token_account(pc_transfer.source)?;
+    let coin_source = unwrap_token_account(coin_transfer.source)?;
+    let pc_mint = pc_source.mint;
+    let coin_mint = coin_source.mint;
     let lp_mint = lp_mint_to.mint;
 
-    let user_pc_pre_balance = pc_transfer.source.unwrap().pre_balance;
-    let user_coin_pre_balance = coin_transfer.source.unwrap().pre_balance;
+    let user_pc_pre_balance = pc_source.pre_balance;
+    let user_coin_pre_balance = coin_source.pre_balance;
 
     let market = match parse_raydium_log(instruction) {
         Ok(RayLog::Init(init)) => Some(Pubkey(init.market).to_string()),
@@ -203,24 +258,25 @@ fn _parse_initialize_instruction<'a>(
 fn _parse_deposit_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext
-) -> Result<DepositEvent, String> {
-    let amm = instruction.accounts()[1].to_string();
-    let user = instruction.accounts()[12].to_string();
+) -> Result<DepositEvent, ParseError> {
+    let amm = get_account(instruction, 1)?;
+    let user = get_account(instruction, 12)?;
 
-    let instructions_len = instruction.inner_instructions().len();
-    let pc_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
-    let coin_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 3], context)?;
-    let lp_mint_to = spl_token_substream::parse_mint_to_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
+    let pc_transfer = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 2)?, context)?;
+    let coin_transfer = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 3)?, context)?;
+    let lp_mint_to = spl_token_substream::parse_mint_to_instruction(&get_inner_instruction_from_end(instruction, 1)?, context)?;
 
     let pc_amount = pc_transfer.amount;
     let coin_amount = coin_transfer.amount;
     let lp_amount = lp_mint_to.amount;
-    let pc_mint = pc_transfer.source.as_ref().unwrap().mint.clone();
-    let coin_mint = coin_transfer.source.as_ref().unwrap().mint.clone();
+    let pc_source = unwrap_token_account(pc_transfer.source)?;
+    let coin_source = unwrap_token_account(coin_transfer.source)?;
+    let pc_mint = pc_source.mint;
+    let coin_mint = coin_source.mint;
     let lp_mint = lp_mint_to.mint;
 
-    let user_pc_pre_balance = pc_transfer.source.unwrap().pre_balance;
-    let user_coin_pre_balance = coin_transfer.source.unwrap().pre_balance;
+    let user_pc_pre_balance = pc_source.pre_balance;
+    let user_coin_pre_balance = coin_source.pre_balance;
 
     let (pool_pc_amount, pool_coin_amount, pool_lp_amount) = match parse_raydium_log(instruction) {
         Ok(RayLog::Deposit(deposit)) => {
@@ -249,24 +305,23 @@ fn _parse_deposit_instruction<'a>(
 fn _parse_withdraw_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<WithdrawEvent, String> {
-    let amm = instruction.accounts()[1].to_string();
-    let user = instruction.accounts()[16].to_string();
+) -> Result<WithdrawEvent, ParseError> {
+    let amm = get_account(instruction, 1)?;
+    let user = get_account(instruction, 16)?;
 
-    let instructions_len = instruction.inner_instructions().len();
-    let pc_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
-    let coin_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 3], context)?;
-    let lp_burn = spl_token_substream::parse_burn_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
+    let pc_transfer = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 2)?, context)?;
+    let coin_transfer = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 3)?, context)?;
+    let lp_burn = spl_token_substream::parse_burn_instruction(&get_inner_instruction_from_end(instruction, 1)?, context)?;
 
     let pc_amount = pc_transfer.amount;
     let coin_amount = coin_transfer.amount;
     let lp_amount = lp_burn.amount;
-    let pc_mint = pc_transfer.source.unwrap().mint;
-    let coin_mint = coin_transfer.source.unwrap().mint;
-    let lp_mint = lp_burn.source.unwrap().mint;
+    let pc_mint = unwrap_token_account(pc_transfer.source)?.mint;
+    let coin_mint = unwrap_token_account(coin_transfer.source)?.mint;
+    let lp_mint = unwrap_token_account(lp_burn.source)?.mint;
 
-    let user_pc_pre_balance = pc_transfer.destination.unwrap().pre_balance;
-    let user_coin_pre_balance = coin_transfer.destination.unwrap().pre_balance;
+    let user_pc_pre_balance = unwrap_token_account(pc_transfer.destination)?.pre_balance;
+    let user_coin_pre_balance = unwrap_token_account(coin_transfer.destination)?.pre_balance;
 
     let (pool_pc_amount, pool_coin_amount, pool_lp_amount) = match parse_raydium_log(instruction) {
         Ok(RayLog::Withdraw(withdraw)) => {
@@ -295,19 +350,19 @@ fn _parse_withdraw_instruction<'a>(
 fn _parse_withdraw_pnl_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
-) -> Result<WithdrawPnlEvent, String> {
-    let amm = instruction.accounts()[1].to_string();
-    let user = instruction.accounts()[9].to_string();
+) -> Result<WithdrawPnlEvent, ParseError> {
+    let amm = get_account(instruction, 1)?;
+    let user = get_account(instruction, 9)?;
 
     let instructions_len = instruction.inner_instructions().len();
     if instructions_len == 2 || instructions_len == 3 {
-        let pc_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 1], context)?;
-        let coin_transfer = spl_token_substream::parse_transfer_instruction(&instruction.inner_instructions()[instructions_len - 2], context)?;
+        let pc_transfer = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 1)?, context)?;
+        let coin_transfer = spl_token_substream::parse_transfer_instruction(&get_inner_instruction_from_end(instruction, 2)?, context)?;
 
         let pc_amount = Some(pc_transfer.amount);
         let coin_amount = Some(coin_transfer.amount);
-        let pc_mint = Some(pc_transfer.source.unwrap().mint);
-        let coin_mint = Some(coin_transfer.source.unwrap().mint);
+        let pc_mint = Some(unwrap_token_account(pc_transfer.source)?.mint);
+        let coin_mint = Some(unwrap_token_account(coin_transfer.source)?.mint);
 
         return Ok(WithdrawPnlEvent {
             amm,
@@ -329,20 +384,52 @@ fn _parse_withdraw_pnl_instruction(
     }
 }
 
-fn parse_raydium_log(instruction: &StructuredInstruction) -> Result<RayLog, Error> {
+fn parse_raydium_log(instruction: &StructuredInstruction) -> Result<RayLog, ParseError> {
     let re = regex::Regex::new(r"ray_log: (.+)").unwrap();
-    let log_message = instruction.logs().as_ref().context("Failed to parse logs due to truncation")?.iter().rev().find_map(|log| {
+    let log_message = instruction.logs().as_ref().ok_or(ParseError::TruncatedLogs)?.iter().rev().find_map(|log| {
         if let Log::Program(program_log) = log {
-            Some(program_log.message().unwrap())
+            program_log.message().ok()
         } else {
             None
         }
     });
     match log_message {
         Some(message) => match re.captures(message.as_str()) {
-            Some(captures) => Ok(decode_ray_log(&captures[1])),
-            None => return Err(anyhow!("Failed to capture log message")),
+            Some(captures) => decode_ray_log(&captures[1]).map_err(ParseError::InvalidLog),
+            None => Err(ParseError::MissingLog),
         },
-        None => return Err(anyhow!("Log message not found")),
+        None => Err(ParseError::MissingLog),
     }
 }
+
+fn get_account(instruction: &StructuredInstruction, index: usize) -> Result<String, ParseError> {
+    instruction.accounts().get(index).map(|x| x.to_string()).ok_or(ParseError::MissingAccount(index))
+}
+
+fn get_token_account<'b>(
+    instruction: &StructuredInstruction,
+    context: &'b TransactionContext,
+    index: usize,
+) -> Result<&'b utils::spl_token::TokenAccount<'b>, ParseError> {
+    let address = instruction.accounts().get(index).ok_or(ParseError::MissingAccount(index))?;
+    context.get_token_account(address).ok_or_else(|| ParseError::UnknownTokenAccount(address.to_string()))
+}
+
+/// Returns the inner instruction `offset` positions from the end, `1` being the last one.
+fn get_inner_instruction_from_end<'a>(
+    instruction: &StructuredInstruction<'a>,
+    offset: usize,
+) -> Result<Rc<StructuredInstruction<'a>>, ParseError> {
+    let inner_instructions = instruction.inner_instructions();
+    inner_instructions.len()
+        .checked_sub(offset)
+        .and_then(|index| inner_instructions.get(index))
+        .cloned()
+        .ok_or_else(|| ParseError::UnexpectedInnerInstructions(
+            format!("expected at least {} inner instructions, found {}", offset, inner_instructions.len())
+        ))
+}
+
+fn unwrap_token_account(account: Option<TokenAccount>) -> Result<TokenAccount, ParseError> {
+    account.ok_or_else(|| ParseError::UnexpectedInnerInstructions("Token event without token account".to_string()))
+}
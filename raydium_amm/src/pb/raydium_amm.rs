@@ -1,21 +1,38 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct RaydiumAmmBlockEvents {
     #[prost(message, repeated, tag="2")]
     pub transactions: ::prost::alloc::vec::Vec<RaydiumAmmTransactionEvents>,
+    #[prost(message, optional, tag="3")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::BlockEnvelope>,
+    /// Instructions that failed to parse. Only populated with `error_policy=report`.
+    #[prost(message, repeated, tag="4")]
+    pub parse_failures: ::prost::alloc::vec::Vec<::solana_substreams_common::pb::solana_common::ParseFailure>,
+    #[prost(uint32, tag="5")]
+    pub parse_failure_count: u32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct RaydiumAmmTransactionEvents {
     #[prost(string, tag="1")]
     pub signature: ::prost::alloc::string::String,
     #[prost(message, repeated, tag="2")]
     pub events: ::prost::alloc::vec::Vec<RaydiumAmmEvent>,
+    #[prost(message, optional, tag="3")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionEnvelope>,
+    /// Set on failed transactions, which are only included when `include_failed_transactions` is enabled.
+    #[prost(message, optional, tag="4")]
+    pub error: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionError>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct RaydiumAmmEvent {
+    #[prost(message, optional, tag="6")]
+    pub position: ::core::option::Option<::solana_substreams_common::pb::solana_common::InstructionPosition>,
     #[prost(oneof="raydium_amm_event::Event", tags="1, 2, 3, 4, 5")]
     pub event: ::core::option::Option<raydium_amm_event::Event>,
 }
@@ -23,6 +40,7 @@ pub struct RaydiumAmmEvent {
 pub mod raydium_amm_event {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
     pub enum Event {
         #[prost(message, tag="1")]
         Initialize(super::InitializeEvent),
@@ -38,6 +56,7 @@ pub mod raydium_amm_event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct InitializeEvent {
     #[prost(string, tag="1")]
     pub amm: ::prost::alloc::string::String,
@@ -66,6 +85,7 @@ pub struct InitializeEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct DepositEvent {
     #[prost(string, tag="1")]
     pub amm: ::prost::alloc::string::String,
@@ -96,6 +116,7 @@ pub struct DepositEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct WithdrawEvent {
     #[prost(string, tag="1")]
     pub amm: ::prost::alloc::string::String,
@@ -126,6 +147,7 @@ pub struct WithdrawEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct WithdrawPnlEvent {
     #[prost(string, tag="1")]
     pub amm: ::prost::alloc::string::String,
@@ -142,6 +164,7 @@ pub struct WithdrawPnlEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SwapEvent {
     #[prost(string, tag="1")]
     pub amm: ::prost::alloc::string::String,
@@ -0,0 +1,193 @@
+/// Errors raised by the Raydium AMM program, as returned in `InstructionError::Custom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmmError {
+    /// AlreadyInUse
+    AlreadyInUse = 0,
+    /// InvalidProgramAddress
+    InvalidProgramAddress = 1,
+    /// ExpectedMint
+    ExpectedMint = 2,
+    /// ExpectedAccount
+    ExpectedAccount = 3,
+    /// InvalidCoinVault
+    InvalidCoinVault = 4,
+    /// InvalidPCVault
+    InvalidPCVault = 5,
+    /// InvalidTokenLP
+    InvalidTokenLP = 6,
+    /// InvalidDestTokenCoin
+    InvalidDestTokenCoin = 7,
+    /// InvalidDestTokenPC
+    InvalidDestTokenPC = 8,
+    /// InvalidPoolMint
+    InvalidPoolMint = 9,
+    /// InvalidOpenOrders
+    InvalidOpenOrders = 10,
+    /// InvalidMarket
+    InvalidSerumMarket = 11,
+    /// InvalidMarketProgram
+    InvalidSerumProgram = 12,
+    /// InvalidTargetOrders
+    InvalidTargetOrders = 13,
+    /// InvalidWithdrawQueue
+    InvalidWithdrawQueue = 14,
+    /// InvalidTempLp
+    InvalidTempLp = 15,
+    /// InvalidCoinMint
+    InvalidCoinMint = 16,
+    /// InvalidPCMint
+    InvalidPCMint = 17,
+    /// InvalidOwner
+    InvalidOwner = 18,
+    /// InvalidSupply
+    InvalidSupply = 19,
+    /// InvalidDelegate
+    InvalidDelegate = 20,
+    /// Invalid Sign Account
+    InvalidSignAccount = 21,
+    /// InvalidStatus
+    InvalidStatus = 22,
+    /// Invalid instruction
+    InvalidInstruction = 23,
+    /// Wrong accounts number
+    WrongAccountsNumber = 24,
+    /// Withdraw_transfer is busy
+    WithdrawTransferBusy = 25,
+    /// WithdrawQueue is full
+    WithdrawQueueFull = 26,
+    /// WithdrawQueue is empty
+    WithdrawQueueEmpty = 27,
+    /// Params Set is invalid
+    InvalidParamsSet = 28,
+    /// InvalidInput
+    InvalidInput = 29,
+    /// instruction exceeds desired slippage limit
+    ExceededSlippage = 30,
+    /// CalculationExRateFailure
+    CalculationExRateFailure = 31,
+    /// Checked_Sub Overflow
+    CheckedSubOverflow = 32,
+    /// Checked_Add Overflow
+    CheckedAddOverflow = 33,
+    /// Checked_Mul Overflow
+    CheckedMulOverflow = 34,
+    /// Checked_Div Overflow
+    CheckedDivOverflow = 35,
+    /// Empty Funds
+    CheckedEmptyFunds = 36,
+    /// Calc pnl error
+    CalcPnlError = 37,
+    /// InvalidSplTokenProgram
+    InvalidSplTokenProgram = 38,
+    /// Take Pnl error
+    TakePnlError = 39,
+    /// Insufficient funds
+    InsufficientFunds = 40,
+    /// Conversion to u64 failed with an overflow or underflow
+    ConversionFailure = 41,
+    /// user token input does not match amm
+    InvalidUserToken = 42,
+    /// InvalidSrmMint
+    InvalidSrmMint = 43,
+    /// InvalidSrmToken
+    InvalidSrmToken = 44,
+    /// TooManyOpenOrders
+    TooManyOpenOrders = 45,
+    /// OrderAtSlotIsPlaced
+    OrderAtSlotIsPlaced = 46,
+    /// InvalidSysProgramAddress
+    InvalidSysProgramAddress = 47,
+    /// The provided fee does not match the program owner's constraints
+    InvalidFee = 48,
+    /// Repeat create amm about market
+    RepeatCreateAmm = 49,
+    /// Not allow Zero LP
+    NotAllowZeroLP = 50,
+    /// Token account has a close authority
+    InvalidCloseAuthority = 51,
+    /// Pool token mint has a freeze authority
+    InvalidFreezeAuthority = 52,
+    /// InvalidReferPCMint
+    InvalidReferPCMint = 53,
+    /// InvalidConfigAccount
+    InvalidConfigAccount = 54,
+    /// Repeat create staking config account
+    RepeatCreateConfigAccount = 55,
+    /// Market lotSize is too large
+    MarketLotSizeIsTooLarge = 56,
+    /// Init lp amount is too less(Because 10**lp_decimals amount lp will be locked)
+    InitLpAmountTooLess = 57,
+    /// Unknown Amm Error
+    UnknownAmmError = 58,
+}
+
+impl AmmError {
+    pub fn from_u32(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(AmmError::AlreadyInUse),
+            1 => Some(AmmError::InvalidProgramAddress),
+            2 => Some(AmmError::ExpectedMint),
+            3 => Some(AmmError::ExpectedAccount),
+            4 => Some(AmmError::InvalidCoinVault),
+            5 => Some(AmmError::InvalidPCVault),
+            6 => Some(AmmError::InvalidTokenLP),
+            7 => Some(AmmError::InvalidDestTokenCoin),
+            8 => Some(AmmError::InvalidDestTokenPC),
+            9 => Some(AmmError::InvalidPoolMint),
+            10 => Some(AmmError::InvalidOpenOrders),
+            11 => Some(AmmError::InvalidSerumMarket),
+            12 => Some(AmmError::InvalidSerumProgram),
+            13 => Some(AmmError::InvalidTargetOrders),
+            14 => Some(AmmError::InvalidWithdrawQueue),
+            15 => Some(AmmError::InvalidTempLp),
+            16 => Some(AmmError::InvalidCoinMint),
+            17 => Some(AmmError::InvalidPCMint),
+            18 => Some(AmmError::InvalidOwner),
+            19 => Some(AmmError::InvalidSupply),
+            20 => Some(AmmError::InvalidDelegate),
+            21 => Some(AmmError::InvalidSignAccount),
+            22 => Some(AmmError::InvalidStatus),
+            23 => Some(AmmError::InvalidInstruction),
+            24 => Some(AmmError::WrongAccountsNumber),
+            25 => Some(AmmError::WithdrawTransferBusy),
+            26 => Some(AmmError::WithdrawQueueFull),
+            27 => Some(AmmError::WithdrawQueueEmpty),
+            28 => Some(AmmError::InvalidParamsSet),
+            29 => Some(AmmError::InvalidInput),
+            30 => Some(AmmError::ExceededSlippage),
+            31 => Some(AmmError::CalculationExRateFailure),
+            32 => Some(AmmError::CheckedSubOverflow),
+            33 => Some(AmmError::CheckedAddOverflow),
+            34 => Some(AmmError::CheckedMulOverflow),
+            35 => Some(AmmError::CheckedDivOverflow),
+            36 => Some(AmmError::CheckedEmptyFunds),
+            37 => Some(AmmError::CalcPnlError),
+            38 => Some(AmmError::InvalidSplTokenProgram),
+            39 => Some(AmmError::TakePnlError),
+            40 => Some(AmmError::InsufficientFunds),
+            41 => Some(AmmError::ConversionFailure),
+            42 => Some(AmmError::InvalidUserToken),
+            43 => Some(AmmError::InvalidSrmMint),
+            44 => Some(AmmError::InvalidSrmToken),
+            45 => Some(AmmError::TooManyOpenOrders),
+            46 => Some(AmmError::OrderAtSlotIsPlaced),
+            47 => Some(AmmError::InvalidSysProgramAddress),
+            48 => Some(AmmError::InvalidFee),
+            49 => Some(AmmError::RepeatCreateAmm),
+            50 => Some(AmmError::NotAllowZeroLP),
+            51 => Some(AmmError::InvalidCloseAuthority),
+            52 => Some(AmmError::InvalidFreezeAuthority),
+            53 => Some(AmmError::InvalidReferPCMint),
+            54 => Some(AmmError::InvalidConfigAccount),
+            55 => Some(AmmError::RepeatCreateConfigAccount),
+            56 => Some(AmmError::MarketLotSizeIsTooLarge),
+            57 => Some(AmmError::InitLpAmountTooLess),
+            58 => Some(AmmError::UnknownAmmError),
+            _ => None,
+        }
+    }
+
+    pub fn name(code: u32) -> Option<String> {
+        Self::from_u32(code).map(|x| format!("{:?}", x))
+    }
+}
@@ -408,7 +408,7 @@ impl AmmInstruction {
             5 => Self::MigrateToOpenBook,
             6 => {
                 let (param, rest) = Self::unpack_u8(rest)?;
-                match AmmParams::from_u64(param as u64) {
+                match AmmParams::from_u64(param as u64)? {
                     AmmParams::AmmOwner => {
                         if rest.len() >= 32 {
                             let bytes: [u8; 32] = array_ref![rest, 0, 32].clone();
@@ -500,7 +500,7 @@ impl AmmInstruction {
             }
             12 => {
                 let (param, rest) = Self::unpack_u8(rest)?;
-                match SimulateParams::from_u64(param as u64) {
+                match SimulateParams::from_u64(param as u64)? {
                     SimulateParams::PoolInfo | SimulateParams::RunCrankInfo => {
                         Self::SimulateInfo(SimulateInstruction {
                             param,
@@ -545,8 +545,11 @@ impl AmmInstruction {
                 let (param, rest) = Self::unpack_u8(rest)?;
                 match param {
                     0 | 1 => {
+                        if rest.len() < 32 {
+                            return Err("Invalid instruction data");
+                        }
                         let bytes: [u8; 32] = array_ref![rest, 0, 32].clone();
-                            let owner = Some(Pubkey(bytes));
+                        let owner = Some(Pubkey(bytes));
                         Self::UpdateConfigAccount(ConfigArgs {
                             param,
                             owner,
@@ -669,7 +672,7 @@ impl AmmInstruction {
             }) => {
                 buf.push(6);
                 buf.push(*param);
-                match AmmParams::from_u64(*param as u64) {
+                match AmmParams::from_u64(*param as u64)? {
                     AmmParams::AmmOwner => {
                         let new_pubkey = match new_pubkey {
                             Some(a) => a,
@@ -737,7 +740,7 @@ impl AmmInstruction {
             }) => {
                 buf.push(12);
                 buf.push(*param);
-                match SimulateParams::from_u64(*param as u64) {
+                match SimulateParams::from_u64(*param as u64)? {
                     SimulateParams::PoolInfo | SimulateParams::RunCrankInfo => {}
                     SimulateParams::SwapBaseInInfo => {
                         let swap_base_in = match swap_base_in_value {
@@ -4,3 +4,4 @@ pub mod state;
 pub mod log;
 pub mod instruction;
 pub mod constants;
+pub mod error;
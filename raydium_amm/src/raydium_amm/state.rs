@@ -104,17 +104,17 @@ pub enum AmmStatus {
     WaitingTrade = 7u64,
 }
 impl AmmStatus {
-    pub fn from_u64(status: u64) -> Self {
+    pub fn from_u64(status: u64) -> Result<Self, &'static str> {
         match status {
-            0u64 => AmmStatus::Uninitialized,
-            1u64 => AmmStatus::Initialized,
-            2u64 => AmmStatus::Disabled,
-            3u64 => AmmStatus::WithdrawOnly,
-            4u64 => AmmStatus::LiquidityOnly,
-            5u64 => AmmStatus::OrderBookOnly,
-            6u64 => AmmStatus::SwapOnly,
-            7u64 => AmmStatus::WaitingTrade,
-            _ => unreachable!(),
+            0u64 => Ok(AmmStatus::Uninitialized),
+            1u64 => Ok(AmmStatus::Initialized),
+            2u64 => Ok(AmmStatus::Disabled),
+            3u64 => Ok(AmmStatus::WithdrawOnly),
+            4u64 => Ok(AmmStatus::LiquidityOnly),
+            5u64 => Ok(AmmStatus::OrderBookOnly),
+            6u64 => Ok(AmmStatus::SwapOnly),
+            7u64 => Ok(AmmStatus::WaitingTrade),
+            _ => Err("Invalid AmmStatus value"),
         }
     }
 
@@ -201,16 +201,16 @@ pub enum AmmState {
     PurgeOrderState = 6u64,
 }
 impl AmmState {
-    pub fn from_u64(state: u64) -> Self {
+    pub fn from_u64(state: u64) -> Result<Self, &'static str> {
         match state {
-            0u64 => AmmState::InvlidState,
-            1u64 => AmmState::IdleState,
-            2u64 => AmmState::CancelAllOrdersState,
-            3u64 => AmmState::PlanOrdersState,
-            4u64 => AmmState::CancelOrderState,
-            5u64 => AmmState::PlaceOrdersState,
-            6u64 => AmmState::PurgeOrderState,
-            _ => unreachable!(),
+            0u64 => Ok(AmmState::InvlidState),
+            1u64 => Ok(AmmState::IdleState),
+            2u64 => Ok(AmmState::CancelAllOrdersState),
+            3u64 => Ok(AmmState::PlanOrdersState),
+            4u64 => Ok(AmmState::CancelOrderState),
+            5u64 => Ok(AmmState::PlaceOrdersState),
+            6u64 => Ok(AmmState::PurgeOrderState),
+            _ => Err("Invalid AmmState value"),
         }
     }
 
@@ -257,27 +257,27 @@ pub enum AmmParams {
     UpdateOpenOrder = 17u64,
 }
 impl AmmParams {
-    pub fn from_u64(state: u64) -> Self {
+    pub fn from_u64(state: u64) -> Result<Self, &'static str> {
         match state {
-            0u64 => AmmParams::Status,
-            1u64 => AmmParams::State,
-            2u64 => AmmParams::OrderNum,
-            3u64 => AmmParams::Depth,
-            4u64 => AmmParams::AmountWave,
-            5u64 => AmmParams::MinPriceMultiplier,
-            6u64 => AmmParams::MaxPriceMultiplier,
-            7u64 => AmmParams::MinSize,
-            8u64 => AmmParams::VolMaxCutRatio,
-            9u64 => AmmParams::Fees,
-            10u64 => AmmParams::AmmOwner,
-            11u64 => AmmParams::SetOpenTime,
-            12u64 => AmmParams::LastOrderDistance,
-            13u64 => AmmParams::InitOrderDepth,
-            14u64 => AmmParams::SetSwitchTime,
-            15u64 => AmmParams::ClearOpenTime,
-            16u64 => AmmParams::Seperate,
-            17u64 => AmmParams::UpdateOpenOrder,
-            _ => unreachable!(),
+            0u64 => Ok(AmmParams::Status),
+            1u64 => Ok(AmmParams::State),
+            2u64 => Ok(AmmParams::OrderNum),
+            3u64 => Ok(AmmParams::Depth),
+            4u64 => Ok(AmmParams::AmountWave),
+            5u64 => Ok(AmmParams::MinPriceMultiplier),
+            6u64 => Ok(AmmParams::MaxPriceMultiplier),
+            7u64 => Ok(AmmParams::MinSize),
+            8u64 => Ok(AmmParams::VolMaxCutRatio),
+            9u64 => Ok(AmmParams::Fees),
+            10u64 => Ok(AmmParams::AmmOwner),
+            11u64 => Ok(AmmParams::SetOpenTime),
+            12u64 => Ok(AmmParams::LastOrderDistance),
+            13u64 => Ok(AmmParams::InitOrderDepth),
+            14u64 => Ok(AmmParams::SetSwitchTime),
+            15u64 => Ok(AmmParams::ClearOpenTime),
+            16u64 => Ok(AmmParams::Seperate),
+            17u64 => Ok(AmmParams::UpdateOpenOrder),
+            _ => Err("Invalid AmmParams value"),
         }
     }
 
@@ -313,11 +313,11 @@ pub enum AmmResetFlag {
     ResetNo = 1u64,
 }
 impl AmmResetFlag {
-    pub fn from_u64(flag: u64) -> Self {
+    pub fn from_u64(flag: u64) -> Result<Self, &'static str> {
         match flag {
-            0u64 => AmmResetFlag::ResetYes,
-            1u64 => AmmResetFlag::ResetNo,
-            _ => unreachable!(),
+            0u64 => Ok(AmmResetFlag::ResetYes),
+            1u64 => Ok(AmmResetFlag::ResetNo),
+            _ => Err("Invalid AmmResetFlag value"),
         }
     }
 
@@ -458,13 +458,13 @@ pub enum SimulateParams {
     RunCrankInfo = 3u64,
 }
 impl SimulateParams {
-    pub fn from_u64(flag: u64) -> Self {
+    pub fn from_u64(flag: u64) -> Result<Self, &'static str> {
         match flag {
-            0u64 => SimulateParams::PoolInfo,
-            1u64 => SimulateParams::SwapBaseInInfo,
-            2u64 => SimulateParams::SwapBaseOutInfo,
-            3u64 => SimulateParams::RunCrankInfo,
-            _ => unreachable!(),
+            0u64 => Ok(SimulateParams::PoolInfo),
+            1u64 => Ok(SimulateParams::SwapBaseInInfo),
+            2u64 => Ok(SimulateParams::SwapBaseOutInfo),
+            3u64 => Ok(SimulateParams::RunCrankInfo),
+            _ => Err("Invalid SimulateParams value"),
         }
     }
 
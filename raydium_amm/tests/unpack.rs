@@ -0,0 +1,34 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use proptest::prelude::*;
+
+use raydium_amm_substream::raydium_amm::instruction::{AmmInstruction, SwapInstructionBaseIn};
+use raydium_amm_substream::raydium_amm::log::decode_ray_log;
+
+proptest! {
+    #[test]
+    fn unpack_instruction_is_total(data in prop::collection::vec(any::<u8>(), 0..128)) {
+        let _ = AmmInstruction::unpack(&data);
+    }
+
+    #[test]
+    fn unpack_tagged_instruction_is_total(tag in 0u8..20, data in prop::collection::vec(any::<u8>(), 0..128)) {
+        let _ = AmmInstruction::unpack(&[&[tag], data.as_slice()].concat());
+    }
+
+    #[test]
+    fn swap_base_in_round_trips(amount_in: u64, minimum_amount_out: u64) {
+        let instruction = AmmInstruction::SwapBaseIn(SwapInstructionBaseIn { amount_in, minimum_amount_out });
+        prop_assert_eq!(AmmInstruction::unpack(&instruction.pack().unwrap()), Ok(instruction));
+    }
+
+    #[test]
+    fn decode_ray_log_is_total(log in ".*") {
+        let _ = decode_ray_log(&log);
+    }
+
+    #[test]
+    fn decode_base64_ray_log_is_total(log_type in 0u8..8, data in prop::collection::vec(any::<u8>(), 0..128)) {
+        let _ = decode_ray_log(&STANDARD.encode([&[log_type], data.as_slice()].concat()));
+    }
+}
@@ -0,0 +1,206 @@
+use anyhow::Error;
+use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use substreams_solana_utils as utils;
+use utils::instruction::get_structured_instructions;
+use utils::transaction::get_context;
+use utils::spl_token::TOKEN_PROGRAM_ID;
+use utils::system_program::SYSTEM_PROGRAM_ID;
+
+use solana_substreams_common::block;
+use solana_substreams_common::envelope::get_block_envelope;
+use solana_substreams_common::instruction::get_positioned_instructions;
+use solana_substreams_common::failure::ParseFailures;
+use solana_substreams_common::params::Params;
+use solana_substreams_common::pb::solana_common::TransactionError;
+use solana_substreams_common::transaction_error::get_transaction_error;
+
+use system_program_substream::error::SystemError;
+use system_program_substream::pb::system_program::SystemProgramEvent;
+use spl_token_substream::{fill_transaction_events, is_token_program, LamportTracker};
+use spl_token_substream::error::{TokenError, token_2022_error_name};
+use spl_token_substream::token_2022::TOKEN_2022_PROGRAM_ID;
+use spl_token_substream::pb::spl_token::SplTokenEvent;
+use spl_token_substream::pb::spl_token::spl_token_event;
+use raydium_amm_substream::raydium_amm::constants::RAYDIUM_AMM_PROGRAM_ID;
+use raydium_amm_substream::raydium_amm::error::AmmError;
+use raydium_amm_substream::pb::raydium_amm::RaydiumAmmEvent;
+use pumpfun_substream::pumpfun::PUMPFUN_PROGRAM_ID;
+use pumpfun_substream::pumpfun::error::PumpfunError;
+use pumpfun_substream::pb::pumpfun::PumpfunEvent;
+use mpl_token_metadata_substream::mpl_token_metadata::constants::MPL_TOKEN_METADATA_PROGRAM_ID;
+use mpl_token_metadata_substream::error::metadata_error_name;
+use mpl_token_metadata_substream::pb::mpl_token_metadata::MplTokenMetadataEvent;
+
+pub mod pb;
+use pb::solana_events::*;
+use pb::solana_events::solana_event::Event;
+
+#[substreams::handlers::map]
+fn solana_events(params: String, block: Block) -> Result<SolanaBlockEvents, Error> {
+    get_block_events(&block, &Params::parse(&params)?)
+}
+
+pub fn get_block_events(block: &Block, params: &Params) -> Result<SolanaBlockEvents, Error> {
+    let mut failures = ParseFailures::new(params.error_policy);
+    let transactions = parse_block(block, params, &mut failures)?;
+    Ok(SolanaBlockEvents {
+        transactions,
+        envelope: Some(get_block_envelope(block)),
+        parse_failure_count: failures.count(),
+        parse_failures: failures.into_failures(),
+    })
+}
+
+pub fn parse_block(block: &Block, params: &Params, failures: &mut ParseFailures) -> Result<Vec<SolanaTransactionEvents>, Error> {
+    let program_ids = [
+        SYSTEM_PROGRAM_ID,
+        TOKEN_PROGRAM_ID,
+        TOKEN_2022_PROGRAM_ID,
+        RAYDIUM_AMM_PROGRAM_ID,
+        PUMPFUN_PROGRAM_ID,
+        MPL_TOKEN_METADATA_PROGRAM_ID,
+    ].map(|x| x.to_string());
+    let transactions = block::parse_block(
+        block,
+        params,
+        failures,
+        &program_ids,
+        get_solana_transaction_error,
+        _parse_transaction,
+    )?;
+    Ok(transactions.into_iter().map(|x| SolanaTransactionEvents {
+        signature: x.signature,
+        events: x.events,
+        envelope: Some(x.envelope),
+        error: x.error,
+    }).collect())
+}
+
+/// Parses a successful transaction, handing instructions that fail to parse to `failures`.
+pub fn parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<SolanaEvent>, Error> {
+    if transaction.meta.as_ref().map_or(true, |meta| meta.err.is_some()) {
+        return Ok(Vec::new());
+    }
+    _parse_transaction(transaction, failures)
+}
+
+/// Parses the instructions of a failed transaction up to the point of failure. Instructions
+/// that cannot be parsed are handed to `failures`, but don't fail the block under the `strict`
+/// policy.
+pub fn parse_failed_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<SolanaEvent>, Error> {
+    failures.without_failing(|failures| _parse_transaction(transaction, failures))
+}
+
+/// Walks the instruction tree of the transaction once, handing every instruction to the
+/// decoder of its program.
+fn _parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<SolanaEvent>, Error> {
+    let mut events: Vec<SolanaEvent> = Vec::new();
+
+    let mut context = match get_context(transaction) {
+        Ok(context) => context,
+        Err(error) => return failures.record(&utils::transaction::get_signature(transaction), None, error).map(|_| events),
+    };
+    let instructions = match get_structured_instructions(transaction) {
+        Ok(instructions) => instructions,
+        Err(error) => return failures.record(&context.signature, None, error).map(|_| events),
+    };
+
+    let succeeded = transaction.meta.as_ref().map_or(false, |meta| meta.err.is_none());
+    let mut lamports = LamportTracker::default();
+    // Token events are completed from the whole transaction once it has been walked, so they're
+    // kept aside along with the index of their slot in `events`.
+    let mut token_events: Vec<(usize, SplTokenEvent)> = Vec::new();
+
+    for (i, (position, instruction)) in get_positioned_instructions(&instructions).into_iter().enumerate() {
+        context.update_balance(&instruction.instruction);
+        let program_id = instruction.program_id();
+
+        if program_id == SYSTEM_PROGRAM_ID {
+            if succeeded {
+                lamports.apply_system_instruction(&instruction, &context);
+            }
+            match system_program_substream::parse_instruction(&instruction, &context) {
+                Ok(event) => events.push(SolanaEvent {
+                    event: Some(Event::SystemProgram(SystemProgramEvent {
+                        instruction_index: i as u32,
+                        position: Some(position),
+                        event,
+                    })),
+                }),
+                Err(error) => failures.record(&context.signature, Some(position), error)?,
+            }
+        } else if is_token_program(&instruction) {
+            match spl_token_substream::parse_instruction(&instruction, &context) {
+                Ok(None) => (),
+                Ok(mut event) => {
+                    let wrapped_sol = if succeeded {
+                        lamports.apply_token_event(&instruction, &context, &mut event)
+                    } else {
+                        None
+                    };
+                    let token_program = program_id.to_string();
+                    token_events.push((events.len(), SplTokenEvent {
+                        event,
+                        position: Some(position.clone()),
+                        token_program: token_program.clone(),
+                    }));
+                    events.push(SolanaEvent::default());
+                    if let Some(wrapped_sol) = wrapped_sol {
+                        token_events.push((events.len(), SplTokenEvent {
+                            event: Some(spl_token_event::Event::WrappedSol(wrapped_sol)),
+                            position: Some(position),
+                            token_program,
+                        }));
+                        events.push(SolanaEvent::default());
+                    }
+                },
+                Err(error) => failures.record(&context.signature, Some(position), error)?,
+            }
+        } else if program_id == RAYDIUM_AMM_PROGRAM_ID {
+            match raydium_amm_substream::parse_instruction(&instruction, &context) {
+                Ok(Some(event)) => events.push(SolanaEvent {
+                    event: Some(Event::RaydiumAmm(RaydiumAmmEvent { event: Some(event), position: Some(position) })),
+                }),
+                Ok(None) => (),
+                Err(error) => failures.record(&context.signature, Some(position), error)?,
+            }
+        } else if program_id == PUMPFUN_PROGRAM_ID {
+            match pumpfun_substream::parse_instruction(&instruction, &context) {
+                Ok(Some(event)) => events.push(SolanaEvent {
+                    event: Some(Event::Pumpfun(PumpfunEvent { event: Some(event), position: Some(position) })),
+                }),
+                Ok(None) => (),
+                Err(error) => failures.record(&context.signature, Some(position), error)?,
+            }
+        } else if program_id == MPL_TOKEN_METADATA_PROGRAM_ID {
+            match mpl_token_metadata_substream::parse_instruction(&instruction, &context) {
+                Ok(event) => events.push(SolanaEvent {
+                    event: Some(Event::MplTokenMetadata(MplTokenMetadataEvent { event, position: Some(position) })),
+                }),
+                Err(error) => failures.record(&context.signature, Some(position), error)?,
+            }
+        }
+    }
+
+    let (indices, mut filled_events): (Vec<usize>, Vec<SplTokenEvent>) = token_events.into_iter().unzip();
+    fill_transaction_events(transaction, &mut filled_events);
+    for (index, event) in indices.into_iter().zip(filled_events) {
+        events[index].event = Some(Event::SplToken(event));
+    }
+
+    Ok(events)
+}
+
+/// Decodes the transaction error, naming it when raised by one of the decoded programs.
+pub fn get_solana_transaction_error(transaction: &ConfirmedTransaction) -> Option<TransactionError> {
+    let mut error = get_transaction_error(transaction)?;
+    error.name_custom_error(&SYSTEM_PROGRAM_ID.to_string(), SystemError::name);
+    error.name_custom_error(&TOKEN_PROGRAM_ID.to_string(), TokenError::name);
+    error.name_custom_error(&TOKEN_2022_PROGRAM_ID.to_string(), token_2022_error_name);
+    error.name_custom_error(&RAYDIUM_AMM_PROGRAM_ID.to_string(), AmmError::name);
+    error.name_custom_error(&PUMPFUN_PROGRAM_ID.to_string(), PumpfunError::name);
+    error.name_custom_error(&MPL_TOKEN_METADATA_PROGRAM_ID.to_string(), metadata_error_name);
+    Some(error)
+}
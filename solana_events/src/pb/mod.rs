@@ -0,0 +1,6 @@
+// @generated
+// @@protoc_insertion_point(attribute:solana_events)
+pub mod solana_events {
+    include!("solana_events.rs");
+    // @@protoc_insertion_point(solana_events)
+}
@@ -0,0 +1,56 @@
+// @generated
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct SolanaBlockEvents {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<SolanaTransactionEvents>,
+    #[prost(message, optional, tag="2")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::BlockEnvelope>,
+    /// Instructions that failed to parse. Only populated with `error_policy=report`.
+    #[prost(message, repeated, tag="3")]
+    pub parse_failures: ::prost::alloc::vec::Vec<::solana_substreams_common::pb::solana_common::ParseFailure>,
+    #[prost(uint32, tag="4")]
+    pub parse_failure_count: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct SolanaTransactionEvents {
+    #[prost(string, tag="1")]
+    pub signature: ::prost::alloc::string::String,
+    /// Events of every decoded program, in execution order.
+    #[prost(message, repeated, tag="2")]
+    pub events: ::prost::alloc::vec::Vec<SolanaEvent>,
+    #[prost(message, optional, tag="3")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionEnvelope>,
+    /// Set on failed transactions, which are only included when `include_failed_transactions` is enabled.
+    #[prost(message, optional, tag="4")]
+    pub error: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionError>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct SolanaEvent {
+    #[prost(oneof="solana_event::Event", tags="1, 2, 3, 4, 5")]
+    pub event: ::core::option::Option<solana_event::Event>,
+}
+/// Nested message and enum types in `SolanaEvent`.
+pub mod solana_event {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+    pub enum Event {
+        #[prost(message, tag="1")]
+        SystemProgram(::system_program_substream::pb::system_program::SystemProgramEvent),
+        #[prost(message, tag="2")]
+        SplToken(::spl_token_substream::pb::spl_token::SplTokenEvent),
+        #[prost(message, tag="3")]
+        RaydiumAmm(::raydium_amm_substream::pb::raydium_amm::RaydiumAmmEvent),
+        #[prost(message, tag="4")]
+        Pumpfun(::pumpfun_substream::pb::pumpfun::PumpfunEvent),
+        #[prost(message, tag="5")]
+        MplTokenMetadata(::mpl_token_metadata_substream::pb::mpl_token_metadata::MplTokenMetadataEvent),
+    }
+}
+// @@protoc_insertion_point(module)
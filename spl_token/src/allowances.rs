@@ -0,0 +1,137 @@
+//! Delegates of token accounts and their remaining allowances, kept from the events of
+//! `spl_token_events`.
+//!
+//! - `store_delegates` keeps the owner, mint and delegate of each token account.
+//! - `store_delegated_amounts` keeps the amount each delegate may still transfer or burn. It is
+//!   set by `Approve` and `ApproveChecked`, decreased by transfers and burns signed by the
+//!   delegate, and reset to zero by `Revoke`, a `SetAuthority` of type `AccountOwner` and
+//!   `CloseAccount`.
+//! - `allowance_changes` outputs the changes of allowances along with the owner of the account.
+//!
+//! Both stores write at the position of the event in the block, so `store_delegated_amounts`
+//! can look up the delegate of an account as of the event being applied.
+
+use anyhow::Error;
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    DeltaBigInt, Deltas, StoreGet, StoreGetProto, StoreNew, StoreSet, StoreSetProto, StoreSetSum, StoreSetSumBigInt,
+};
+
+use crate::pb::spl_token::{
+    AllowanceChange, AllowanceChanges, AuthorityType, DelegateRecord, SplTokenBlockEvents,
+};
+use crate::pb::spl_token::spl_token_event::Event;
+use crate::get_ordered_events;
+
+#[substreams::handlers::store]
+fn store_delegates(events: SplTokenBlockEvents, store: StoreSetProto<DelegateRecord>) {
+    for (ordinal, event) in get_ordered_events(&events) {
+        let (account, owner, delegate) = match event {
+            Event::Approve(x) => (&x.source, None, Some(x.delegate.clone())),
+            Event::Revoke(x) => (&x.source, None, None),
+            Event::SetAuthority(x) if x.authority_type == AuthorityType::AccountOwner as i32 => {
+                match &x.new_authority {
+                    Some(new_authority) => (&x.token_account, Some(new_authority), None),
+                    None => continue,
+                }
+            },
+            Event::CloseAccount(x) => (&x.source, None, None),
+            _ => continue,
+        };
+        if let Some(account) = account {
+            let record = DelegateRecord {
+                owner: owner.unwrap_or(&account.owner).clone(),
+                mint: account.mint.clone(),
+                delegate,
+            };
+            store.set(ordinal, &account.address, &record);
+        }
+    }
+}
+
+#[substreams::handlers::store]
+fn store_delegated_amounts(
+    events: SplTokenBlockEvents,
+    delegates: StoreGetProto<DelegateRecord>,
+    store: StoreSetSumBigInt,
+) {
+    for (ordinal, event) in get_ordered_events(&events) {
+        match event {
+            Event::Approve(x) => if let Some(source) = &x.source {
+                store.set(ordinal, &source.address, BigInt::from(x.amount));
+            },
+            Event::Revoke(x) => if let Some(source) = &x.source {
+                store.set(ordinal, &source.address, BigInt::zero());
+            },
+            Event::SetAuthority(x) if x.authority_type == AuthorityType::AccountOwner as i32 => {
+                if let Some(token_account) = &x.token_account {
+                    store.set(ordinal, &token_account.address, BigInt::zero());
+                }
+            },
+            Event::CloseAccount(x) => if let Some(source) = &x.source {
+                store.set(ordinal, &source.address, BigInt::zero());
+            },
+            Event::Transfer(x) => if let Some(source) = &x.source {
+                if is_delegate(&delegates, ordinal, &source.address, &x.authority) {
+                    store.sum(ordinal, &source.address, -BigInt::from(x.amount));
+                }
+            },
+            Event::Burn(x) => if let Some(source) = &x.source {
+                if is_delegate(&delegates, ordinal, &source.address, &x.authority) {
+                    store.sum(ordinal, &source.address, -BigInt::from(x.amount));
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Whether `authority` is the delegate of the token account as of `ordinal`.
+fn is_delegate(delegates: &StoreGetProto<DelegateRecord>, ordinal: u64, account: &str, authority: &str) -> bool {
+    delegates.get_at(ordinal, account)
+        .and_then(|x| x.delegate)
+        .map_or(false, |x| x == authority)
+}
+
+/// Record of the token account as of just before the event at `ordinal`.
+fn get_previous_record(delegates: &StoreGetProto<DelegateRecord>, ordinal: u64, account: &str) -> Option<DelegateRecord> {
+    match ordinal.checked_sub(1) {
+        Some(ordinal) => delegates.get_at(ordinal, account),
+        None => delegates.get_first(account),
+    }
+}
+
+#[substreams::handlers::map]
+fn allowance_changes(
+    clock: Clock,
+    deltas: Deltas<DeltaBigInt>,
+    delegates: StoreGetProto<DelegateRecord>,
+) -> Result<AllowanceChanges, Error> {
+    let zero = BigInt::zero();
+    let changes = deltas.deltas.into_iter()
+        // Resetting an account that had no allowance changes nothing.
+        .filter(|x| x.old_value != zero || x.new_value != zero)
+        .filter_map(|x| {
+            let mut record = delegates.get_at(x.ordinal, &x.key)?;
+            // Resets clear the delegate along with the allowance, so the allowance being reset is
+            // reported with the delegate and owner it had.
+            if record.delegate.is_none() {
+                record = get_previous_record(&delegates, x.ordinal, &x.key).unwrap_or(record);
+            }
+            Some(AllowanceChange {
+                owner: record.owner,
+                token_account: x.key,
+                mint: record.mint,
+                delegate: record.delegate,
+                old_amount: x.old_value.to_string(),
+                new_amount: x.new_value.to_string(),
+            })
+        })
+        .collect();
+    Ok(AllowanceChanges {
+        slot: clock.number,
+        block_time: clock.timestamp.map(|x| x.seconds),
+        changes,
+    })
+}
@@ -0,0 +1,101 @@
+//! Mint and freeze authorities of mints, and frozen token accounts, kept from the events of
+//! `spl_token_events`.
+//!
+//! - `store_mint_authorities` keeps the mint authority of each mint under
+//!   `mint_authority:{mint}`, and its freeze authority under `freeze_authority:{mint}`, from
+//!   `InitializeMint` and `SetAuthority`. A mint without the authority has an empty value.
+//! - `store_frozen_accounts` keeps whether each token account is frozen, as 1 or 0, under
+//!   `{mint}:{account}`, from `FreezeAccount` and `ThawAccount`.
+//! - `mint_authority_changes` outputs the changes of authorities, flagging the revoked ones. The
+//!   previous authority is the one that signed the change, so revocations of mints created before
+//!   the start block are reported too.
+
+use anyhow::Error;
+use substreams::store::{StoreNew, StoreSet, StoreSetInt64, StoreSetString};
+
+use crate::pb::spl_token::{AuthorityType, MintAuthorityChange, MintAuthorityChanges, SplTokenBlockEvents};
+use crate::pb::spl_token::spl_token_event::Event;
+use crate::get_ordered_events;
+
+const MINT_AUTHORITY_PREFIX: &str = "mint_authority";
+const FREEZE_AUTHORITY_PREFIX: &str = "freeze_authority";
+
+#[substreams::handlers::store]
+fn store_mint_authorities(events: SplTokenBlockEvents, store: StoreSetString) {
+    for (ordinal, event) in get_ordered_events(&events) {
+        let authorities = match event {
+            Event::InitializeMint(x) => vec![
+                (MINT_AUTHORITY_PREFIX, &x.mint, Some(&x.mint_authority)),
+                (FREEZE_AUTHORITY_PREFIX, &x.mint, x.freeze_authority.as_ref()),
+            ],
+            Event::SetAuthority(x) if x.authority_type == AuthorityType::MintTokens as i32 => {
+                vec![(MINT_AUTHORITY_PREFIX, &x.mint, x.new_authority.as_ref())]
+            },
+            Event::SetAuthority(x) if x.authority_type == AuthorityType::FreezeAccount as i32 => {
+                vec![(FREEZE_AUTHORITY_PREFIX, &x.mint, x.new_authority.as_ref())]
+            },
+            _ => continue,
+        };
+        for (prefix, mint, authority) in authorities {
+            let authority = authority.cloned().unwrap_or_default();
+            store.set(ordinal, format!("{}:{}", prefix, mint), &authority);
+        }
+    }
+}
+
+#[substreams::handlers::store]
+fn store_frozen_accounts(events: SplTokenBlockEvents, store: StoreSetInt64) {
+    for (ordinal, event) in get_ordered_events(&events) {
+        let (account, frozen) = match event {
+            Event::FreezeAccount(x) => (&x.source, 1),
+            Event::ThawAccount(x) => (&x.source, 0),
+            _ => continue,
+        };
+        if let Some(account) = account {
+            store.set(ordinal, format!("{}:{}", account.mint, account.address), &frozen);
+        }
+    }
+}
+
+#[substreams::handlers::map]
+fn mint_authority_changes(events: SplTokenBlockEvents) -> Result<MintAuthorityChanges, Error> {
+    let mut changes = Vec::new();
+    for (_, event) in get_ordered_events(&events) {
+        match event {
+            Event::InitializeMint(x) => {
+                changes.push(get_change(&x.mint, AuthorityType::MintTokens, None, Some(&x.mint_authority)));
+                if x.freeze_authority.is_some() {
+                    changes.push(get_change(&x.mint, AuthorityType::FreezeAccount, None, x.freeze_authority.as_ref()));
+                }
+            },
+            Event::SetAuthority(x) => {
+                let authority_type = AuthorityType::from_i32(x.authority_type);
+                if let Some(authority_type @ (AuthorityType::MintTokens | AuthorityType::FreezeAccount)) = authority_type {
+                    changes.push(get_change(&x.mint, authority_type, x.previous_authority.as_ref(), x.new_authority.as_ref()));
+                }
+            },
+            _ => (),
+        }
+    }
+    let envelope = events.envelope.unwrap_or_default();
+    Ok(MintAuthorityChanges {
+        slot: envelope.slot,
+        block_time: envelope.block_time,
+        changes,
+    })
+}
+
+fn get_change(
+    mint: &str,
+    authority_type: AuthorityType,
+    old_authority: Option<&String>,
+    new_authority: Option<&String>,
+) -> MintAuthorityChange {
+    MintAuthorityChange {
+        mint: mint.to_string(),
+        authority_type: authority_type.into(),
+        old_authority: old_authority.cloned(),
+        new_authority: new_authority.cloned(),
+        revoked: old_authority.is_some() && new_authority.is_none(),
+    }
+}
@@ -0,0 +1,128 @@
+//! Latest token balances and holder counts, kept from the events of `spl_token_events`.
+//!
+//! - `store_token_account_balances` keeps the latest state of each token account: its owner,
+//!   mint and balance.
+//! - `store_owner_balances` keeps the balance of each owner in each mint, the sum of the
+//!   balances of its token accounts, keyed by `{mint}:{owner}`.
+//! - `store_mint_holders` keeps the number of owners with a positive balance of each mint,
+//!   output as changes by `mint_holder_count_deltas`.
+//!
+//! Only token accounts seen since the start block count, so the holder count of a mint created
+//! before the start block leaves out the holders whose accounts haven't been touched since.
+
+use anyhow::Error;
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    DeltaBigInt, DeltaInt64, DeltaProto, Deltas, StoreAdd, StoreAddBigInt, StoreAddInt64, StoreNew, StoreSet,
+    StoreSetProto,
+};
+
+use solana_substreams_common::pb::solana_common::TokenAccount;
+
+use crate::pb::spl_token::{AuthorityType, MintHolderCountDelta, MintHolderCountDeltas, SplTokenBlockEvents};
+use crate::pb::spl_token::spl_token_event::Event;
+use crate::get_ordered_events;
+
+#[substreams::handlers::store]
+fn store_token_account_balances(events: SplTokenBlockEvents, store: StoreSetProto<TokenAccount>) {
+    for (ordinal, event) in get_ordered_events(&events) {
+        for account in get_token_account_states(event) {
+            store.set(ordinal, &account.address, &account);
+        }
+    }
+}
+
+/// States token accounts are left in by the event. Balances are the accounts' post-balances.
+pub fn get_token_account_states(event: &Event) -> Vec<TokenAccount> {
+    let accounts = match event {
+        Event::Transfer(x) => vec![&x.source, &x.destination],
+        Event::MintTo(x) => vec![&x.destination],
+        Event::Burn(x) => vec![&x.source],
+        Event::InitializeAccount(x) => vec![&x.account],
+        Event::SyncNative(x) => vec![&x.account],
+        Event::WithdrawWithheldTokensFromMint(x) => vec![&x.destination],
+        Event::WithdrawWithheldTokensFromAccounts(x) => vec![&x.destination],
+        Event::CloseAccount(x) => {
+            // The account is gone, along with anything left in it.
+            return x.source.iter().map(|x| TokenAccount { post_balance: Some(0), ..x.clone() }).collect();
+        },
+        Event::SetAuthority(x) if x.authority_type == AuthorityType::AccountOwner as i32 => {
+            return match (&x.token_account, &x.new_authority) {
+                (Some(account), Some(owner)) => vec![TokenAccount { owner: owner.clone(), ..account.clone() }],
+                _ => Vec::new(),
+            };
+        },
+        _ => return Vec::new(),
+    };
+    accounts.into_iter()
+        .flatten()
+        .filter(|x| x.post_balance.is_some())
+        .cloned()
+        .collect()
+}
+
+#[substreams::handlers::store]
+fn store_owner_balances(deltas: Deltas<DeltaProto<TokenAccount>>, store: StoreAddBigInt) {
+    for delta in deltas.deltas {
+        for (key, change) in get_owner_balance_changes(&delta.old_value, &delta.new_value) {
+            store.add(delta.ordinal, key, change);
+        }
+    }
+}
+
+/// Changes of owner balances, keyed by `{mint}:{owner}`, made by a token account going from
+/// the `old` to the `new` state. `old` is the default for an account seen for the first time.
+pub fn get_owner_balance_changes(old: &TokenAccount, new: &TokenAccount) -> Vec<(String, BigInt)> {
+    let old_balance = BigInt::from(old.post_balance.unwrap_or(0));
+    let new_balance = BigInt::from(new.post_balance.unwrap_or(0));
+    let key = owner_balance_key(&new.mint, &new.owner);
+    if old.owner == new.owner && old.mint == new.mint {
+        return vec![(key, new_balance - old_balance)];
+    }
+    // The account changed owner, or is seen for the first time.
+    let mut changes = Vec::new();
+    if !old.owner.is_empty() {
+        changes.push((owner_balance_key(&old.mint, &old.owner), -old_balance));
+    }
+    changes.push((key, new_balance));
+    changes
+}
+
+fn owner_balance_key(mint: &str, owner: &str) -> String {
+    format!("{}:{}", mint, owner)
+}
+
+#[substreams::handlers::store]
+fn store_mint_holders(deltas: Deltas<DeltaBigInt>, store: StoreAddInt64) {
+    for delta in deltas.deltas {
+        if let Some((mint, change)) = get_holder_count_change(&delta.key, &delta.old_value, &delta.new_value) {
+            store.add(delta.ordinal, mint, change);
+        }
+    }
+}
+
+/// Change of the holder count of the mint, made by the balance of an owner under `key`
+/// (`{mint}:{owner}`) going from `old_balance` to `new_balance`.
+pub fn get_holder_count_change<'a>(key: &'a str, old_balance: &BigInt, new_balance: &BigInt) -> Option<(&'a str, i64)> {
+    let (mint, _owner) = key.split_once(':')?;
+    let zero = BigInt::zero();
+    match (*old_balance > zero, *new_balance > zero) {
+        (false, true) => Some((mint, 1)),
+        (true, false) => Some((mint, -1)),
+        _ => None,
+    }
+}
+
+#[substreams::handlers::map]
+fn mint_holder_count_deltas(clock: Clock, deltas: Deltas<DeltaInt64>) -> Result<MintHolderCountDeltas, Error> {
+    Ok(MintHolderCountDeltas {
+        slot: clock.number,
+        block_time: clock.timestamp.map(|x| x.seconds),
+        deltas: deltas.deltas.into_iter().map(|x| MintHolderCountDelta {
+            mint: x.key,
+            old_holder_count: x.old_value,
+            new_holder_count: x.new_value,
+        }).collect(),
+    })
+}
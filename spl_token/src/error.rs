@@ -0,0 +1,138 @@
+use thiserror::Error;
+
+/// Errors raised by the Token program, as returned in `InstructionError::Custom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenError {
+    /// Lamport balance below rent-exempt threshold.
+    NotRentExempt = 0,
+    /// Insufficient funds for the operation requested.
+    InsufficientFunds = 1,
+    /// Invalid Mint.
+    InvalidMint = 2,
+    /// Account not associated with this Mint.
+    MintMismatch = 3,
+    /// Owner does not match.
+    OwnerMismatch = 4,
+    /// This token's supply is fixed and new tokens cannot be minted.
+    FixedSupply = 5,
+    /// The account cannot be initialized because it is already being used.
+    AlreadyInUse = 6,
+    /// Invalid number of provided signers.
+    InvalidNumberOfProvidedSigners = 7,
+    /// Invalid number of required signers.
+    InvalidNumberOfRequiredSigners = 8,
+    /// State is uninitialized.
+    UninitializedState = 9,
+    /// Instruction does not support native tokens.
+    NativeNotSupported = 10,
+    /// Non-native account can only be closed if its balance is zero.
+    NonNativeHasBalance = 11,
+    /// Invalid instruction.
+    InvalidInstruction = 12,
+    /// State is invalid for requested operation.
+    InvalidState = 13,
+    /// Operation overflowed.
+    Overflow = 14,
+    /// Account does not support specified authority type.
+    AuthorityTypeNotSupported = 15,
+    /// This token mint cannot freeze accounts.
+    MintCannotFreeze = 16,
+    /// Account is frozen; all account operations will fail.
+    AccountFrozen = 17,
+    /// Mint decimals mismatch between the client and mint.
+    MintDecimalsMismatch = 18,
+    /// Instruction does not support non-native tokens.
+    NonNativeNotSupported = 19,
+}
+
+impl TokenError {
+    pub fn from_u32(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(TokenError::NotRentExempt),
+            1 => Some(TokenError::InsufficientFunds),
+            2 => Some(TokenError::InvalidMint),
+            3 => Some(TokenError::MintMismatch),
+            4 => Some(TokenError::OwnerMismatch),
+            5 => Some(TokenError::FixedSupply),
+            6 => Some(TokenError::AlreadyInUse),
+            7 => Some(TokenError::InvalidNumberOfProvidedSigners),
+            8 => Some(TokenError::InvalidNumberOfRequiredSigners),
+            9 => Some(TokenError::UninitializedState),
+            10 => Some(TokenError::NativeNotSupported),
+            11 => Some(TokenError::NonNativeHasBalance),
+            12 => Some(TokenError::InvalidInstruction),
+            13 => Some(TokenError::InvalidState),
+            14 => Some(TokenError::Overflow),
+            15 => Some(TokenError::AuthorityTypeNotSupported),
+            16 => Some(TokenError::MintCannotFreeze),
+            17 => Some(TokenError::AccountFrozen),
+            18 => Some(TokenError::MintDecimalsMismatch),
+            19 => Some(TokenError::NonNativeNotSupported),
+            _ => None,
+        }
+    }
+
+    pub fn name(code: u32) -> Option<String> {
+        Self::from_u32(code).map(|x| format!("{:?}", x))
+    }
+}
+
+/// Names of the errors Token-2022 raises on top of `TokenError`, starting at code 20.
+const TOKEN_2022_ERRORS: &[&str] = &[
+    "ExtensionTypeMismatch",
+    "ExtensionBaseMismatch",
+    "ExtensionAlreadyInitialized",
+    "ConfidentialTransferAccountHasBalance",
+    "ConfidentialTransferAccountNotApproved",
+    "ConfidentialTransferDepositsAndTransfersDisabled",
+    "ConfidentialTransferElGamalPubkeyMismatch",
+    "ConfidentialTransferBalanceMismatch",
+    "MintHasSupply",
+    "NoAuthorityExists",
+    "TransferFeeExceedsMaximum",
+    "MintRequiredForTransfer",
+    "FeeMismatch",
+    "FeeParametersMismatch",
+    "ImmutableOwner",
+    "AccountHasWithheldTransferFees",
+    "NoMemo",
+    "NonTransferable",
+    "NonTransferableNeedsImmutableOwnership",
+    "MaximumPendingBalanceCreditCounterExceeded",
+    "MaximumDepositAmountExceeded",
+    "CpiGuardSettingsLocked",
+    "CpiGuardTransferBlocked",
+    "CpiGuardBurnBlocked",
+    "CpiGuardCloseAccountBlocked",
+    "CpiGuardApproveBlocked",
+    "CpiGuardSetAuthorityBlocked",
+    "CpiGuardOwnerChangeBlocked",
+    "ExtensionNotFound",
+    "NonConfidentialTransfersDisabled",
+    "ConfidentialTransferFeeAccountHasWithheldFee",
+    "InvalidExtensionCombination",
+    "InvalidLengthForAlloc",
+];
+
+/// Names a Token-2022 error, which shares its first codes with `TokenError`.
+pub fn token_2022_error_name(code: u32) -> Option<String> {
+    TokenError::name(code).or_else(|| {
+        let index = code.checked_sub(20)?;
+        TOKEN_2022_ERRORS.get(index as usize).map(|x| x.to_string())
+    })
+}
+
+/// Errors raised while parsing a Token program instruction.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("Not a Token or Token-2022 program instruction")]
+    InvalidProgram,
+    #[error("Failed to unpack Token instruction: {0}")]
+    Unpack(String),
+    #[error("Missing account at index {0}")]
+    MissingAccount(usize),
+    #[error("Unknown token account {0}")]
+    UnknownTokenAccount(String),
+    #[error("Expected a {0} instruction")]
+    UnexpectedInstruction(&'static str),
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use substreams_solana_utils as utils;
+use utils::instruction::StructuredInstruction;
+use utils::transaction::TransactionContext;
+use utils::system_program::SystemInstruction;
+
+use solana_substreams_common::account::get_account_balance;
+
+use crate::pb::spl_token::{WrappedSolDirection, WrappedSolEvent};
+use crate::pb::spl_token::spl_token_event::Event;
+use crate::NATIVE_MINTS;
+
+/// Follows the lamport balances of accounts through a successful transaction, to fill in the
+/// lamports moved by `CloseAccount` and `SyncNative`, which their instructions don't state.
+///
+/// Balances start from the transaction's pre-balances and are moved by the System program
+/// transfers and account creations and the Token instructions that move lamports. A token
+/// account can only be debited by its token program, so its balance is exact as long as it
+/// isn't credited directly by another program. Native accounts are assumed to be synced at the
+/// start of the transaction.
+#[derive(Default)]
+pub struct LamportTracker {
+    balances: HashMap<String, u64>,
+    /// Lamports of each native account reflected in its token amount.
+    synced: HashMap<String, u64>,
+    /// Accounts that transferred lamports into each native account since its last sync.
+    funding_accounts: HashMap<String, Vec<String>>,
+}
+
+impl LamportTracker {
+    /// Applies a System program instruction.
+    pub fn apply_system_instruction(&mut self, instruction: &StructuredInstruction, context: &TransactionContext) {
+        let (lamports, from, to) = match SystemInstruction::unpack(&instruction.data()) {
+            Ok(SystemInstruction::CreateAccount(create_account)) => (create_account.lamports, 0, 1),
+            Ok(SystemInstruction::CreateAccountWithSeed(create_account_with_seed)) => (create_account_with_seed.lamports, 0, 1),
+            Ok(SystemInstruction::Transfer(transfer)) => (transfer.lamports, 0, 1),
+            Ok(SystemInstruction::TransferWithSeed(transfer_with_seed)) => (transfer_with_seed.lamports, 0, 2),
+            _ => return,
+        };
+        let (from, to) = match (self.load(instruction, context, from), self.load(instruction, context, to)) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return,
+        };
+        self.debit(&from, lamports);
+        self.credit(&to, lamports);
+        self.funding_accounts.entry(to).or_default().push(from);
+    }
+
+    /// Applies a Token instruction, given its parsed event. Fills in the lamports of
+    /// `CloseAccount` and `SyncNative` and returns the wrapped SOL movement they make, if any.
+    pub fn apply_token_event(
+        &mut self,
+        instruction: &StructuredInstruction,
+        context: &TransactionContext,
+        event: &mut Option<Event>,
+    ) -> Option<WrappedSolEvent> {
+        match event {
+            Some(Event::InitializeAccount(initialize_account)) => {
+                let account = initialize_account.account.as_ref()?;
+                if is_native(&account.mint) {
+                    let address = self.load(instruction, context, 0)?;
+                    self.synced.insert(address.clone(), self.balances[&address]);
+                    self.funding_accounts.remove(&address);
+                }
+                None
+            },
+            Some(Event::Transfer(transfer)) => {
+                // Transfers of wrapped SOL move the lamports along with the amount.
+                let source = transfer.source.as_ref()?;
+                let destination = transfer.destination.as_ref()?;
+                if is_native(&source.mint) {
+                    let (source, destination) = (source.address.clone(), destination.address.clone());
+                    self.load_address(instruction, context, &source)?;
+                    self.load_address(instruction, context, &destination)?;
+                    self.debit(&source, transfer.amount);
+                    self.credit(&destination, transfer.amount);
+                    let synced = self.synced.get(&source).copied().unwrap_or(0).saturating_sub(transfer.amount);
+                    self.synced.insert(source, synced);
+                    *self.synced.entry(destination).or_insert(0) += transfer.amount;
+                }
+                None
+            },
+            Some(Event::SyncNative(sync_native)) => {
+                let address = self.load(instruction, context, 0)?;
+                let balance = self.balances[&address];
+                let synced = self.synced.insert(address.clone(), balance).unwrap_or(balance);
+                let lamports = balance.saturating_sub(synced);
+                sync_native.lamports = Some(lamports);
+                let funding_accounts = self.funding_accounts.remove(&address).unwrap_or_default();
+                (lamports > 0).then(|| WrappedSolEvent {
+                    direction: WrappedSolDirection::Wrap.into(),
+                    account: sync_native.account.clone(),
+                    lamports,
+                    funding_accounts,
+                    destination: None,
+                })
+            },
+            Some(Event::CloseAccount(close_account)) => {
+                let address = self.load(instruction, context, 0)?;
+                let destination = self.load(instruction, context, 1)?;
+                let lamports = self.balances[&address];
+                self.debit(&address, lamports);
+                self.credit(&destination, lamports);
+                self.synced.remove(&address);
+                self.funding_accounts.remove(&address);
+                close_account.lamports = Some(lamports);
+                let account = close_account.source.as_ref()?;
+                is_native(&account.mint).then(|| WrappedSolEvent {
+                    direction: WrappedSolDirection::Unwrap.into(),
+                    account: close_account.source.clone(),
+                    lamports,
+                    funding_accounts: Vec::new(),
+                    destination: Some(destination),
+                })
+            },
+            _ => None,
+        }
+    }
+
+    /// Starts following the account at `index` in the instruction's accounts, from its
+    /// pre-balance, and returns its address.
+    fn load(&mut self, instruction: &StructuredInstruction, context: &TransactionContext, index: usize) -> Option<String> {
+        let address = instruction.accounts().get(index)?.to_string();
+        if !self.balances.contains_key(&address) {
+            let balance = get_account_balance(instruction, context, index)?;
+            self.balances.insert(address.clone(), balance.pre_balance);
+            self.synced.entry(address.clone()).or_insert(balance.pre_balance);
+        }
+        Some(address)
+    }
+
+    fn load_address(&mut self, instruction: &StructuredInstruction, context: &TransactionContext, address: &str) -> Option<()> {
+        let index = instruction.accounts().iter().position(|x| x.to_string() == address)?;
+        self.load(instruction, context, index).map(|_| ())
+    }
+
+    fn credit(&mut self, address: &str, lamports: u64) {
+        let balance = self.balances.entry(address.to_string()).or_insert(0);
+        *balance = balance.saturating_add(lamports);
+    }
+
+    fn debit(&mut self, address: &str, lamports: u64) {
+        let balance = self.balances.entry(address.to_string()).or_insert(0);
+        *balance = balance.saturating_sub(lamports);
+    }
+}
+
+fn is_native(mint: &str) -> bool {
+    NATIVE_MINTS.iter().any(|x| x.to_string() == mint)
+}
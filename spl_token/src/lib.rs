@@ -1,168 +1,849 @@
-use anyhow::{anyhow, Context, Error};
+use std::collections::HashMap;
+
+use anyhow::Error;
 
 use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::pb::sf::solana::r#type::v1::Block;
+use substreams_solana::b58;
 
 use substreams_solana_utils as utils;
-use utils::instruction::{get_structured_instructions, StructuredInstruction, StructuredInstructions};
+use utils::instruction::{get_structured_instructions, StructuredInstruction};
 use utils::transaction::{get_context, TransactionContext};
 use utils::spl_token::{TokenInstruction, TOKEN_PROGRAM_ID};
+use utils::system_program::SYSTEM_PROGRAM_ID;
 use utils::pubkey::Pubkey;
 
+use solana_substreams_common::block;
+use solana_substreams_common::envelope::get_block_envelope;
+use solana_substreams_common::instruction::get_positioned_instructions;
+use solana_substreams_common::failure::ParseFailures;
+use solana_substreams_common::params::Params;
+use solana_substreams_common::pb::solana_common::{TokenAccount, TransactionError};
+use solana_substreams_common::transaction_error::get_transaction_error;
+
 pub mod pb;
 use pb::spl_token::*;
 use pb::spl_token::spl_token_event::Event;
 
+pub mod error;
+use error::{ParseError, TokenError, token_2022_error_name};
+
+pub mod token_2022;
+use token_2022::{TOKEN_2022_PROGRAM_ID, NATIVE_MINT_2022};
+use token_2022::instruction::Token2022Instruction;
+use token_2022::extension::transfer_fee::TransferFeeInstruction;
+use token_2022::extension::confidential_transfer::ConfidentialTransferInstruction;
+use token_2022::extension::default_account_state::DefaultAccountStateInstruction;
+use token_2022::extension::memo_transfer::MemoTransferInstruction;
+use token_2022::extension::interest_bearing_mint::InterestBearingMintInstruction;
+use token_2022::extension::cpi_guard::CpiGuardInstruction;
+use token_2022::extension::transfer_hook::TransferHookInstruction;
+use token_2022::extension::confidential_transfer_fee::ConfidentialTransferFeeInstruction;
+use token_2022::extension::metadata_pointer::MetadataPointerInstruction;
+use token_2022::extension::group_pointer::GroupPointerInstruction;
+use token_2022::extension::scaled_ui_amount::ScaledUiAmountInstruction;
+use token_2022::extension::pausable::PausableInstruction;
+use token_2022::extension::extension_type_name;
+use token_2022::interface::token_metadata::{self, TokenMetadataInstruction};
+use token_2022::interface::token_group::TokenGroupInstruction;
+
+mod lamports;
+pub use lamports::LamportTracker;
+
+mod supply;
+mod balances;
+pub use balances::{get_token_account_states, get_owner_balance_changes, get_holder_count_change};
+mod allowances;
+mod authorities;
+mod registry;
+pub use registry::get_registry_events;
+use registry::TokenAccountRegistry;
+
+/// Programs decoded by this module: the Token program and Token-2022.
+pub const TOKEN_PROGRAM_IDS: [Pubkey; 2] = [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID];
+
+pub const NATIVE_MINT: Pubkey = Pubkey(b58!("So11111111111111111111111111111111111111112"));
+/// Mints of wrapped SOL, of the Token program and Token-2022.
+pub const NATIVE_MINTS: [Pubkey; 2] = [NATIVE_MINT, NATIVE_MINT_2022];
+
 #[substreams::handlers::map]
-fn spl_token_events(block: Block) -> Result<SplTokenBlockEvents, Error> {
-    Ok(SplTokenBlockEvents { transactions: parse_block(&block)? })
-}
-
-pub fn parse_block(block: &Block) -> Result<Vec<SplTokenTransactionEvents>, Error> {
-    let mut transactions_events: Vec<SplTokenTransactionEvents> = Vec::new();
-    for transaction in block.transactions() {
-        let events = parse_transaction(transaction)?;
-        if !events.is_empty() {
-            transactions_events.push(SplTokenTransactionEvents {
-                signature: utils::transaction::get_signature(&transaction),
-                events
-            })
-        }
-    }
-    Ok(transactions_events)
+fn spl_token_events(params: String, block: Block) -> Result<SplTokenBlockEvents, Error> {
+    get_block_events(&block, &Params::parse(&params)?)
+}
+
+pub fn get_block_events(block: &Block, params: &Params) -> Result<SplTokenBlockEvents, Error> {
+    _get_block_events(block, params, None)
+}
+
+pub(crate) fn _get_block_events(
+    block: &Block,
+    params: &Params,
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<SplTokenBlockEvents, Error> {
+    let mut failures = ParseFailures::new(params.error_policy);
+    let transactions = _parse_block(block, params, &mut failures, registry)?;
+    Ok(SplTokenBlockEvents {
+        transactions,
+        envelope: Some(get_block_envelope(block)),
+        parse_failure_count: failures.count(),
+        parse_failures: failures.into_failures(),
+    })
+}
+
+pub fn parse_block(block: &Block, params: &Params, failures: &mut ParseFailures) -> Result<Vec<SplTokenTransactionEvents>, Error> {
+    _parse_block(block, params, failures, None)
 }
 
-pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<SplTokenEvent>, Error> {
-    if let Some(_) = transaction.meta.as_ref().unwrap().err {
+/// Parses the block, resolving the token accounts the transactions' metadata doesn't describe
+/// through `registry`.
+pub(crate) fn _parse_block(
+    block: &Block,
+    params: &Params,
+    failures: &mut ParseFailures,
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<Vec<SplTokenTransactionEvents>, Error> {
+    let transactions = block::parse_block(
+        block,
+        params,
+        failures,
+        &[TOKEN_PROGRAM_ID.to_string(), TOKEN_2022_PROGRAM_ID.to_string()],
+        get_token_transaction_error,
+        |transaction, failures| _parse_transaction(transaction, failures, registry),
+    )?;
+    Ok(transactions.into_iter().map(|x| SplTokenTransactionEvents {
+        signature: x.signature,
+        events: x.events,
+        envelope: Some(x.envelope),
+        error: x.error,
+    }).collect())
+}
+
+/// Parses a successful transaction, handing instructions that fail to parse to `failures`.
+pub fn parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<SplTokenEvent>, Error> {
+    if transaction.meta.as_ref().map_or(true, |meta| meta.err.is_some()) {
         return Ok(Vec::new())
     }
+    _parse_transaction(transaction, failures, None)
+}
+
+/// Parses the instructions of a failed transaction up to the point of failure. Instructions
+/// that cannot be parsed, typically because the state they reference was never produced, are
+/// handed to `failures`, but don't fail the block under the `strict` policy.
+pub fn parse_failed_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<SplTokenEvent>, Error> {
+    failures.without_failing(|failures| _parse_transaction(transaction, failures, None))
+}
 
+fn _parse_transaction(
+    transaction: &ConfirmedTransaction,
+    failures: &mut ParseFailures,
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<Vec<SplTokenEvent>, Error> {
     let mut events: Vec<SplTokenEvent> = Vec::new();
 
-    let mut context = get_context(transaction)?;
-    let instructions = get_structured_instructions(transaction)?;
+    let mut context = match get_context(transaction) {
+        Ok(context) => context,
+        Err(error) => return failures.record(&utils::transaction::get_signature(transaction), None, error).map(|_| events),
+    };
+    let instructions = match get_structured_instructions(transaction) {
+        Ok(instructions) => instructions,
+        Err(error) => return failures.record(&context.signature, None, error).map(|_| events),
+    };
+
+    // A failed transaction leaves balances untouched, so there is nothing to infer fees and
+    // lamports from.
+    let succeeded = transaction.meta.as_ref().map_or(false, |meta| meta.err.is_none());
+    let mut lamports = LamportTracker::default();
 
-    for instruction in instructions.flattened().iter() {
+    for (position, instruction) in get_positioned_instructions(&instructions) {
         context.update_balance(&instruction.instruction);
-        if instruction.program_id() == TOKEN_PROGRAM_ID {
-            let event = parse_instruction(instruction, &context)?;
-            events.push(SplTokenEvent { event });
+        if succeeded && instruction.program_id() == SYSTEM_PROGRAM_ID {
+            lamports.apply_system_instruction(&instruction, &context);
+        }
+        if is_token_program(&instruction) {
+            match _parse_instruction(&instruction, &context, registry) {
+                Ok(None) => (),
+                Ok(mut event) => {
+                    let wrapped_sol = if succeeded {
+                        lamports.apply_token_event(&instruction, &context, &mut event)
+                    } else {
+                        None
+                    };
+                    let token_program = instruction.program_id().to_string();
+                    events.push(SplTokenEvent {
+                        event,
+                        position: Some(position.clone()),
+                        token_program: token_program.clone(),
+                    });
+                    if let Some(wrapped_sol) = wrapped_sol {
+                        events.push(SplTokenEvent {
+                            event: Some(Event::WrappedSol(wrapped_sol)),
+                            position: Some(position),
+                            token_program,
+                        });
+                    }
+                },
+                Err(error) => failures.record(&context.signature, Some(position), error)?,
+            }
         }
     }
+    fill_transaction_events(transaction, &mut events);
 
     Ok(events)
 }
 
+/// Fills in what the Token events of a transaction can only get from the transaction as a
+/// whole: the fees withheld from transfers, the results of instructions that set return data and
+/// the roles of delegates. `events` are all the Token events of the transaction, in order.
+pub fn fill_transaction_events(transaction: &ConfirmedTransaction, events: &mut [SplTokenEvent]) {
+    if transaction.meta.as_ref().map_or(false, |meta| meta.err.is_none()) {
+        fill_withheld_fees(events);
+        fill_return_data(transaction, events);
+    }
+    fill_delegate_roles(events);
+}
+
+/// Fills the fee of Token-2022 transfers that don't state it, from the balance the destination
+/// actually received. Only done when the transfer is the sole event touching the destination
+/// in the transaction, since otherwise its balance change can't be attributed to the transfer,
+/// and not for transfers of an account to itself, whose balance doesn't change.
+fn fill_withheld_fees(events: &mut [SplTokenEvent]) {
+    let token_2022_program_id = TOKEN_2022_PROGRAM_ID.to_string();
+    let mut fees: Vec<(usize, u64)> = Vec::new();
+    for (index, event) in events.iter().enumerate() {
+        let transfer = match &event.event {
+            Some(Event::Transfer(transfer)) if transfer.fee.is_none() && event.token_program == token_2022_program_id => transfer,
+            _ => continue,
+        };
+        let destination = match &transfer.destination {
+            Some(destination) => destination,
+            None => continue,
+        };
+        if transfer.source.as_ref().map_or(false, |x| x.address == destination.address) {
+            continue;
+        }
+        if events.iter().filter(|x| touches_token_account(x, &destination.address)).count() != 1 {
+            continue;
+        }
+        let received = match (destination.pre_balance, destination.post_balance) {
+            (Some(pre_balance), Some(post_balance)) => post_balance.checked_sub(pre_balance),
+            _ => None,
+        };
+        if let Some(fee) = received.and_then(|x| transfer.amount.checked_sub(x)) {
+            fees.push((index, fee));
+        }
+    }
+    for (index, fee) in fees {
+        if let Some(Event::Transfer(transfer)) = &mut events[index].event {
+            transfer.fee = Some(fee);
+        }
+    }
+}
+
+/// Fills the role of transfer and burn authorities that aren't the owner of the source account,
+/// from the delegates approved and the permanent delegates set earlier in the transaction.
+fn fill_delegate_roles(events: &mut [SplTokenEvent]) {
+    let mut delegates: HashMap<String, String> = HashMap::new();
+    let mut permanent_delegates: HashMap<String, String> = HashMap::new();
+    for event in events.iter_mut() {
+        let (source, authority, authority_role) = match &mut event.event {
+            Some(Event::Approve(approve)) => {
+                if let Some(source) = &approve.source {
+                    delegates.insert(source.address.clone(), approve.delegate.clone());
+                }
+                continue;
+            },
+            Some(Event::Revoke(revoke)) => {
+                if let Some(source) = &revoke.source {
+                    delegates.remove(&source.address);
+                }
+                continue;
+            },
+            Some(Event::InitializePermanentDelegate(initialize_permanent_delegate)) => {
+                permanent_delegates.insert(initialize_permanent_delegate.mint.clone(), initialize_permanent_delegate.delegate.clone());
+                continue;
+            },
+            Some(Event::SetAuthority(set_authority)) if set_authority.authority_type == AuthorityType::PermanentDelegate as i32 => {
+                match &set_authority.new_authority {
+                    Some(new_authority) => permanent_delegates.insert(set_authority.mint.clone(), new_authority.clone()),
+                    None => permanent_delegates.remove(&set_authority.mint),
+                };
+                continue;
+            },
+            Some(Event::Transfer(transfer)) => (&transfer.source, &transfer.authority, &mut transfer.authority_role),
+            Some(Event::Burn(burn)) => (&burn.source, &burn.authority, &mut burn.authority_role),
+            _ => continue,
+        };
+        let source = match source {
+            Some(source) if *authority_role == AuthorityRole::UnknownDelegate as i32 => source,
+            _ => continue,
+        };
+        if delegates.get(&source.address) == Some(authority) {
+            *authority_role = AuthorityRole::Delegate.into();
+        } else if permanent_delegates.get(&source.mint) == Some(authority) {
+            *authority_role = AuthorityRole::PermanentDelegate.into();
+        }
+    }
+}
+
+/// Fills the return data of the last `AmountToUiAmount`, `UiAmountToAmount` or
+/// `GetAccountDataSize` from the transaction's return data, which is the last one set in the
+/// transaction. Only done when it was set by the same token program, since no instruction of
+/// another program can have set it afterwards then.
+fn fill_return_data(transaction: &ConfirmedTransaction, events: &mut [SplTokenEvent]) {
+    let return_data = match transaction.meta.as_ref().and_then(|x| x.return_data.as_ref()) {
+        Some(return_data) => return_data,
+        None => return,
+    };
+    let program_id = bs58::encode(&return_data.program_id).into_string();
+    let event = events.iter_mut().rev().find(|x| matches!(
+        x.event,
+        Some(Event::AmountToUiAmount(_)) | Some(Event::UiAmountToAmount(_)) | Some(Event::GetAccountDataSize(_))
+    ));
+    let event = match event {
+        Some(event) if event.token_program == program_id => event,
+        _ => return,
+    };
+    let data = &return_data.data;
+    match &mut event.event {
+        Some(Event::AmountToUiAmount(x)) => x.ui_amount = String::from_utf8(data.clone()).ok(),
+        Some(Event::UiAmountToAmount(x)) => x.amount = data.as_slice().try_into().ok().map(u64::from_le_bytes),
+        Some(Event::GetAccountDataSize(x)) => x.data_size = data.as_slice().try_into().ok().map(u64::from_le_bytes),
+        _ => (),
+    }
+}
+
+/// Whether the event moves tokens in or out of the token account `address`.
+fn touches_token_account(event: &SplTokenEvent, address: &str) -> bool {
+    let is = |x: &Option<TokenAccount>| {
+        x.as_ref().map_or(false, |x| x.address == address)
+    };
+    match &event.event {
+        Some(Event::Transfer(x)) => is(&x.source) || is(&x.destination),
+        Some(Event::MintTo(x)) => is(&x.destination),
+        Some(Event::Burn(x)) => is(&x.source),
+        Some(Event::CloseAccount(x)) => is(&x.source),
+        Some(Event::SyncNative(x)) => is(&x.account),
+        Some(Event::WithdrawWithheldTokensFromMint(x)) => is(&x.destination),
+        Some(Event::WithdrawWithheldTokensFromAccounts(x)) => is(&x.destination) || x.sources.iter().any(|x| x == address),
+        Some(Event::HarvestWithheldTokensToMint(x)) => x.sources.iter().any(|x| x == address),
+        // Deposits and withdrawals move tokens between the public and the confidential balance.
+        Some(Event::ConfidentialTransfer(x)) => {
+            let deposit_or_withdraw = x.instruction_type == ConfidentialTransferInstructionType::Deposit as i32
+                || x.instruction_type == ConfidentialTransferInstructionType::Withdraw as i32;
+            deposit_or_withdraw && x.account == address
+        },
+        _ => false,
+    }
+}
+
+/// Events of the block's successful transactions, with their position in the block as ordinal.
+/// Stores kept from the events write at these ordinals.
+pub(crate) fn get_ordered_events(events: &SplTokenBlockEvents) -> impl Iterator<Item = (u64, &Event)> {
+    events.transactions.iter()
+        .filter(|x| x.error.is_none())
+        .flat_map(|x| x.events.iter())
+        .filter_map(|x| x.event.as_ref())
+        .enumerate()
+        .map(|(ordinal, event)| (ordinal as u64, event))
+}
+
+/// Decodes the transaction error, naming it when raised by the Token program or Token-2022.
+pub fn get_token_transaction_error(transaction: &ConfirmedTransaction) -> Option<TransactionError> {
+    let mut error = get_transaction_error(transaction)?;
+    error.name_custom_error(&TOKEN_PROGRAM_ID.to_string(), TokenError::name);
+    error.name_custom_error(&TOKEN_2022_PROGRAM_ID.to_string(), token_2022_error_name);
+    Some(error)
+}
+
+/// Whether the instruction belongs to the Token program or Token-2022.
+pub fn is_token_program(instruction: &StructuredInstruction) -> bool {
+    TOKEN_PROGRAM_IDS.iter().any(|x| instruction.program_id() == *x)
+}
+
 pub fn parse_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<Option<Event>, Error> {
-    if instruction.program_id() != TOKEN_PROGRAM_ID {
-        return Err(anyhow!("Not a Token program instruction"));
+) -> Result<Option<Event>, ParseError> {
+    _parse_instruction(instruction, context, None)
+}
+
+/// Parses the instruction, resolving the token accounts the transaction's metadata doesn't
+/// describe through `registry`.
+fn _parse_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<Option<Event>, ParseError> {
+    if instruction.program_id() == TOKEN_PROGRAM_ID {
+        let unpacked = TokenInstruction::unpack(&instruction.data())
+            .map_err(|x| ParseError::Unpack(x.to_string()))?;
+        _parse_token_instruction(instruction, context, registry, unpacked)
+    } else if instruction.program_id() == TOKEN_2022_PROGRAM_ID {
+        match Token2022Instruction::unpack(&instruction.data()).map_err(ParseError::Unpack)? {
+            Token2022Instruction::Token(unpacked) => _parse_token_instruction(instruction, context, registry, unpacked),
+            Token2022Instruction::SetAuthority { authority_type, new_authority } => {
+                // The proto enum reserves 0 for `Null`, and otherwise follows the program's discriminants.
+                let authority_type = AuthorityType::from_i32(i32::from(authority_type) + 1)
+                    .ok_or_else(|| ParseError::Unpack(format!("Invalid authority type {}", authority_type)))?;
+                let event = _parse_set_authority_instruction(instruction, context, registry, authority_type, new_authority);
+                event.map(|x| Some(Event::SetAuthority(x)))
+            },
+            Token2022Instruction::InitializeMintCloseAuthority { close_authority } => {
+                let event = _parse_initialize_mint_close_authority_instruction(instruction, context, close_authority);
+                event.map(|x| Some(Event::InitializeMintCloseAuthority(x)))
+            },
+            Token2022Instruction::InitializeNonTransferableMint => {
+                let event = _parse_initialize_non_transferable_mint_instruction(instruction, context);
+                event.map(|x| Some(Event::InitializeNonTransferableMint(x)))
+            },
+            Token2022Instruction::InitializePermanentDelegate { delegate } => {
+                let event = _parse_initialize_permanent_delegate_instruction(instruction, context, delegate);
+                event.map(|x| Some(Event::InitializePermanentDelegate(x)))
+            },
+            Token2022Instruction::Reallocate { extension_types } => {
+                let event = _parse_reallocate_instruction(instruction, context, extension_types);
+                event.map(|x| Some(Event::Reallocate(x)))
+            },
+            Token2022Instruction::DefaultAccountState(unpacked) => _parse_default_account_state_instruction(instruction, context, unpacked),
+            Token2022Instruction::MemoTransfer(unpacked) => {
+                let enabled = matches!(unpacked, MemoTransferInstruction::Enable);
+                let event = _parse_memo_transfer_instruction(instruction, context, registry, enabled);
+                event.map(|x| Some(Event::MemoTransfer(x)))
+            },
+            Token2022Instruction::CpiGuard(unpacked) => {
+                let enabled = matches!(unpacked, CpiGuardInstruction::Enable);
+                let event = _parse_cpi_guard_instruction(instruction, context, registry, enabled);
+                event.map(|x| Some(Event::CpiGuard(x)))
+            },
+            Token2022Instruction::InterestBearingMint(unpacked) => _parse_interest_bearing_mint_instruction(instruction, context, unpacked),
+            Token2022Instruction::TransferHook(unpacked) => _parse_transfer_hook_instruction(instruction, context, unpacked),
+            Token2022Instruction::ScaledUiAmount(unpacked) => _parse_scaled_ui_amount_instruction(instruction, context, unpacked),
+            Token2022Instruction::Pausable(unpacked) => _parse_pausable_instruction(instruction, context, unpacked),
+            Token2022Instruction::TransferFee(unpacked) => _parse_transfer_fee_instruction(instruction, context, registry, unpacked),
+            Token2022Instruction::ConfidentialTransfer(unpacked) => {
+                let event = _parse_confidential_transfer_instruction(instruction, context, unpacked);
+                event.map(|x| Some(Event::ConfidentialTransfer(x)))
+            },
+            Token2022Instruction::ConfidentialTransferFee(unpacked) => {
+                let event = _parse_confidential_transfer_fee_instruction(instruction, context, unpacked);
+                event.map(|x| Some(Event::ConfidentialTransferFee(x)))
+            },
+            Token2022Instruction::MetadataPointer(unpacked) => _parse_metadata_pointer_instruction(instruction, context, unpacked),
+            Token2022Instruction::GroupPointer(unpacked) => _parse_group_pointer_instruction(instruction, context, unpacked),
+            Token2022Instruction::TokenMetadata(unpacked) => _parse_token_metadata_instruction(instruction, context, unpacked),
+            Token2022Instruction::TokenGroup(unpacked) => _parse_token_group_instruction(instruction, context, unpacked),
+            Token2022Instruction::Extension(_) => Ok(None),
+        }
+    } else {
+        Err(ParseError::InvalidProgram)
     }
+}
 
-    let unpacked = TokenInstruction::unpack(&instruction.data())
-        .map_err(|x| anyhow!(x).context("Failed to unpack Token instruction"))?;
+/// Parses an instruction of the instruction set shared by the Token program and Token-2022.
+fn _parse_token_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
+    unpacked: TokenInstruction,
+) -> Result<Option<Event>, ParseError> {
     match unpacked {
         TokenInstruction::InitializeMint { decimals, mint_authority, freeze_authority } |
         TokenInstruction::InitializeMint2 { decimals, mint_authority, freeze_authority } => {
             let event = _parse_initialize_mint_instruction(instruction, context, decimals as u32, mint_authority, freeze_authority);
-            event.map(|x| Some(Event::InitializeMint(x))).map_err(|x| anyhow!(x))
+            event.map(|x| Some(Event::InitializeMint(x)))
         },
 
         TokenInstruction::InitializeAccount => {
             let event = _parse_initialize_account_instruction(instruction, context, None);
-            event.map(|x| Some(Event::InitializeAccount(x))).map_err(|x| anyhow!(x))
+            event.map(|x| Some(Event::InitializeAccount(x)))
         },
         TokenInstruction::InitializeAccount2 { owner } |
         TokenInstruction::InitializeAccount3 { owner } => {
             let event = _parse_initialize_account_instruction(instruction, context, Some(owner));
-            event.map(|x| Some(Event::InitializeAccount(x))).map_err(|x| anyhow!(x))
+            event.map(|x| Some(Event::InitializeAccount(x)))
         },
 
         TokenInstruction::InitializeMultisig { m } => {
             let event = _parse_initialize_multisig_instruction(instruction, context, m, true);
-            event.map(|x| Some(Event::InitializeMultisig(x))).map_err(|x| anyhow!(x))
+            event.map(|x| Some(Event::InitializeMultisig(x)))
         }
         TokenInstruction::InitializeMultisig2 { m } => {
             let event = _parse_initialize_multisig_instruction(instruction, context, m, false);
-            event.map(|x| Some(Event::InitializeMultisig(x))).map_err(|x| anyhow!(x))
+            event.map(|x| Some(Event::InitializeMultisig(x)))
         },
 
         TokenInstruction::Transfer { amount } => {
-            let event = _parse_transfer_instruction(instruction, context, amount, None);
-            event.map(|x| Some(Event::Transfer(x))).map_err(|x| anyhow!(x))
+            let event = _parse_transfer_instruction(instruction, context, registry, amount, None);
+            event.map(|x| Some(Event::Transfer(x)))
         },
         TokenInstruction::TransferChecked { amount, decimals } => {
-            let event = _parse_transfer_instruction(instruction, context, amount, Some(decimals));
-            event.map(|x| Some(Event::Transfer(x))).map_err(|x| anyhow!(x))
+            let event = _parse_transfer_instruction(instruction, context, registry, amount, Some(decimals));
+            event.map(|x| Some(Event::Transfer(x)))
         },
 
         TokenInstruction::Approve { amount } => {
-            let event = _parse_approve_instruction(instruction, context, amount, None);
-            event.map(|x| Some(Event::Approve(x))).map_err(|x| anyhow!(x))
+            let event = _parse_approve_instruction(instruction, context, registry, amount, None);
+            event.map(|x| Some(Event::Approve(x)))
         },
         TokenInstruction::ApproveChecked { amount, decimals } => {
-            let event = _parse_approve_instruction(instruction, context, amount, Some(decimals));
-            event.map(|x| Some(Event::Approve(x))).map_err(|x| anyhow!(x))
+            let event = _parse_approve_instruction(instruction, context, registry, amount, Some(decimals));
+            event.map(|x| Some(Event::Approve(x)))
         },
 
         TokenInstruction::Revoke => {
-            let event = _parse_revoke_instruction(instruction, context);
-            event.map(|x| Some(Event::Revoke(x))).map_err(|x| anyhow!(x))
+            let event = _parse_revoke_instruction(instruction, context, registry);
+            event.map(|x| Some(Event::Revoke(x)))
         },
 
         TokenInstruction::SetAuthority { authority_type, new_authority } => {
-            let event = _parse_set_authority_instruction(instruction, context, authority_type, new_authority);
-            event.map(|x| Some(Event::SetAuthority(x))).map_err(|x| anyhow!(x))
+            let authority_type = match authority_type {
+                utils::spl_token::AuthorityType::MintTokens => AuthorityType::MintTokens,
+                utils::spl_token::AuthorityType::FreezeAccount => AuthorityType::FreezeAccount,
+                utils::spl_token::AuthorityType::AccountOwner => AuthorityType::AccountOwner,
+                utils::spl_token::AuthorityType::CloseAccount => AuthorityType::CloseAccount,
+            };
+            let event = _parse_set_authority_instruction(instruction, context, registry, authority_type, new_authority);
+            event.map(|x| Some(Event::SetAuthority(x)))
         },
 
         TokenInstruction::MintTo { amount } => {
-            let event = _parse_mint_to_instruction(instruction, context, amount);
-            event.map(|x| Some(Event::MintTo(x))).map_err(|x| anyhow!(x))
+            let event = _parse_mint_to_instruction(instruction, context, registry, amount);
+            event.map(|x| Some(Event::MintTo(x)))
         },
         TokenInstruction::MintToChecked { amount, decimals: _ } => {
-            let event = _parse_mint_to_instruction(instruction, context, amount);
-            event.map(|x| Some(Event::MintTo(x))).map_err(|x| anyhow!(x))
+            let event = _parse_mint_to_instruction(instruction, context, registry, amount);
+            event.map(|x| Some(Event::MintTo(x)))
         },
 
         TokenInstruction::Burn { amount } => {
-            let event = _parse_burn_instruction(instruction, context, amount);
-            event.map(|x| Some(Event::Burn(x))).map_err(|x| anyhow!(x))
+            let event = _parse_burn_instruction(instruction, context, registry, amount);
+            event.map(|x| Some(Event::Burn(x)))
         },
         TokenInstruction::BurnChecked { amount, decimals: _ } => {
-            let event = _parse_burn_instruction(instruction, context, amount);
-            event.map(|x| Some(Event::Burn(x))).map_err(|x| anyhow!(x))
+            let event = _parse_burn_instruction(instruction, context, registry, amount);
+            event.map(|x| Some(Event::Burn(x)))
         },
 
         TokenInstruction::CloseAccount => {
-            let event = _parse_close_account_instruction(instruction, context);
-            event.map(|x| Some(Event::CloseAccount(x))).map_err(|x| anyhow!(x))
+            let event = _parse_close_account_instruction(instruction, context, registry);
+            event.map(|x| Some(Event::CloseAccount(x)))
         },
 
         TokenInstruction::FreezeAccount => {
-            let event = _parse_freeze_account_instruction(instruction, context);
-            event.map(|x| Some(Event::FreezeAccount(x))).map_err(|x| anyhow!(x))
+            let event = _parse_freeze_account_instruction(instruction, context, registry);
+            event.map(|x| Some(Event::FreezeAccount(x)))
         },
 
         TokenInstruction::ThawAccount => {
-            let event = _parse_thaw_account_instruction(instruction, context);
-            event.map(|x| Some(Event::ThawAccount(x))).map_err(|x| anyhow!(x))
+            let event = _parse_thaw_account_instruction(instruction, context, registry);
+            event.map(|x| Some(Event::ThawAccount(x)))
         },
 
         TokenInstruction::InitializeImmutableOwner => {
-            let event = _parse_initialize_immutable_owner_instruction(instruction, context);
-            event.map(|x| Some(Event::InitializeImmutableOwner(x))).map_err(|x| anyhow!(x))
+            let event = _parse_initialize_immutable_owner_instruction(instruction, context, registry);
+            event.map(|x| Some(Event::InitializeImmutableOwner(x)))
         },
 
         TokenInstruction::SyncNative => {
-            let event = _parse_sync_native_instruction(instruction, context);
-            event.map(|x| Some(Event::SyncNative(x))).map_err(|x| anyhow!(x))
+            let event = _parse_sync_native_instruction(instruction, context, registry);
+            event.map(|x| Some(Event::SyncNative(x)))
+        },
+
+        TokenInstruction::AmountToUiAmount { amount } => {
+            let event = _parse_amount_to_ui_amount_instruction(instruction, context, amount);
+            event.map(|x| Some(Event::AmountToUiAmount(x)))
+        },
+
+        TokenInstruction::UiAmountToAmount { ui_amount } => {
+            let event = _parse_ui_amount_to_amount_instruction(instruction, context, ui_amount);
+            event.map(|x| Some(Event::UiAmountToAmount(x)))
+        },
+
+        TokenInstruction::GetAccountDataSize => {
+            let event = _parse_get_account_data_size_instruction(instruction, context);
+            event.map(|x| Some(Event::GetAccountDataSize(x)))
+        },
+    }
+}
+
+fn _parse_amount_to_ui_amount_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    amount: u64,
+) -> Result<AmountToUiAmountEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(AmountToUiAmountEvent {
+        mint,
+        amount,
+        ui_amount: None,
+    })
+}
+
+fn _parse_ui_amount_to_amount_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    ui_amount: &str,
+) -> Result<UiAmountToAmountEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(UiAmountToAmountEvent {
+        mint,
+        ui_amount: ui_amount.to_string(),
+        amount: None,
+    })
+}
+
+fn _parse_get_account_data_size_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<GetAccountDataSizeEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    // Token-2022 takes the extension types to make room for after the instruction type.
+    let data = instruction.data();
+    let extension_types = data.get(1..).unwrap_or_default()
+        .chunks_exact(2)
+        .map(|x| extension_type_name(u16::from_le_bytes([x[0], x[1]])))
+        .collect();
+
+    Ok(GetAccountDataSizeEvent {
+        mint,
+        extension_types,
+        data_size: None,
+    })
+}
+
+/// Parses an instruction of the Token-2022 TransferFee extension.
+fn _parse_transfer_fee_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
+    unpacked: TransferFeeInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        TransferFeeInstruction::InitializeTransferFeeConfig { transfer_fee_config_authority, withdraw_withheld_authority, transfer_fee_basis_points, maximum_fee } => {
+            let event = _parse_initialize_transfer_fee_config_instruction(instruction, context, transfer_fee_config_authority, withdraw_withheld_authority, transfer_fee_basis_points, maximum_fee);
+            event.map(|x| Some(Event::InitializeTransferFeeConfig(x)))
+        },
+
+        TransferFeeInstruction::TransferCheckedWithFee { amount, decimals, fee } => {
+            let event = _parse_transfer_instruction(instruction, context, registry, amount, Some(decimals));
+            event.map(|x| Some(Event::Transfer(TransferEvent { fee: Some(fee), ..x })))
+        },
+
+        TransferFeeInstruction::WithdrawWithheldTokensFromMint => {
+            let event = _parse_withdraw_withheld_tokens_from_mint_instruction(instruction, context, registry);
+            event.map(|x| Some(Event::WithdrawWithheldTokensFromMint(x)))
+        },
+
+        TransferFeeInstruction::WithdrawWithheldTokensFromAccounts { num_token_accounts } => {
+            let event = _parse_withdraw_withheld_tokens_from_accounts_instruction(instruction, context, registry, num_token_accounts);
+            event.map(|x| Some(Event::WithdrawWithheldTokensFromAccounts(x)))
+        },
+
+        TransferFeeInstruction::HarvestWithheldTokensToMint => {
+            let event = _parse_harvest_withheld_tokens_to_mint_instruction(instruction, context);
+            event.map(|x| Some(Event::HarvestWithheldTokensToMint(x)))
+        },
+
+        TransferFeeInstruction::SetTransferFee { transfer_fee_basis_points, maximum_fee } => {
+            let event = _parse_set_transfer_fee_instruction(instruction, context, transfer_fee_basis_points, maximum_fee);
+            event.map(|x| Some(Event::SetTransferFee(x)))
+        },
+    }
+}
+
+/// Parses an instruction of the Token-2022 DefaultAccountState extension.
+fn _parse_default_account_state_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    unpacked: DefaultAccountStateInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        DefaultAccountStateInstruction::Initialize { state } => {
+            let event = _parse_initialize_default_account_state_instruction(instruction, context, state);
+            event.map(|x| Some(Event::InitializeDefaultAccountState(x)))
+        },
+        DefaultAccountStateInstruction::Update { state } => {
+            let event = _parse_update_default_account_state_instruction(instruction, context, state);
+            event.map(|x| Some(Event::UpdateDefaultAccountState(x)))
+        },
+    }
+}
+
+/// Parses an instruction of the Token-2022 InterestBearingMint extension.
+fn _parse_interest_bearing_mint_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    unpacked: InterestBearingMintInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        InterestBearingMintInstruction::Initialize { rate_authority, rate } => {
+            let event = _parse_initialize_interest_bearing_mint_instruction(instruction, context, rate_authority, rate);
+            event.map(|x| Some(Event::InitializeInterestBearingMint(x)))
+        },
+        InterestBearingMintInstruction::UpdateRate { rate } => {
+            let event = _parse_update_interest_bearing_mint_rate_instruction(instruction, context, rate);
+            event.map(|x| Some(Event::UpdateInterestBearingMintRate(x)))
+        },
+    }
+}
+
+/// Parses an instruction of the Token-2022 TransferHook extension.
+fn _parse_transfer_hook_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    unpacked: TransferHookInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        TransferHookInstruction::Initialize { authority, program_id } => {
+            let event = _parse_initialize_transfer_hook_instruction(instruction, context, authority, program_id);
+            event.map(|x| Some(Event::InitializeTransferHook(x)))
         },
-        TokenInstruction::AmountToUiAmount { amount: _ } => Ok(None),
-        TokenInstruction::GetAccountDataSize => Ok(None),
-        TokenInstruction::UiAmountToAmount { ui_amount: _ } => Ok(None),
-    }.context("Failed to parse Token instruction")
+        TransferHookInstruction::Update { program_id } => {
+            let event = _parse_update_transfer_hook_instruction(instruction, context, program_id);
+            event.map(|x| Some(Event::UpdateTransferHook(x)))
+        },
+    }
+}
+
+/// Parses an instruction of the Token-2022 ScaledUiAmount extension.
+fn _parse_scaled_ui_amount_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    unpacked: ScaledUiAmountInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        ScaledUiAmountInstruction::Initialize { authority, multiplier } => {
+            let event = _parse_initialize_scaled_ui_amount_instruction(instruction, context, authority, multiplier);
+            event.map(|x| Some(Event::InitializeScaledUiAmount(x)))
+        },
+        ScaledUiAmountInstruction::UpdateMultiplier { multiplier, effective_timestamp } => {
+            let event = _parse_update_scaled_ui_amount_multiplier_instruction(instruction, context, multiplier, effective_timestamp);
+            event.map(|x| Some(Event::UpdateScaledUiAmountMultiplier(x)))
+        },
+    }
+}
+
+/// Parses an instruction of the Token-2022 Pausable extension.
+fn _parse_pausable_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    unpacked: PausableInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        PausableInstruction::Initialize { authority } => {
+            let event = _parse_initialize_pausable_instruction(instruction, context, authority);
+            event.map(|x| Some(Event::InitializePausable(x)))
+        },
+        PausableInstruction::Pause => {
+            let event = _parse_pause_instruction(instruction, context);
+            event.map(|x| Some(Event::Pause(x)))
+        },
+        PausableInstruction::Resume => {
+            let event = _parse_resume_instruction(instruction, context);
+            event.map(|x| Some(Event::Resume(x)))
+        },
+    }
+}
+
+/// Parses an instruction of the Token-2022 MetadataPointer extension.
+fn _parse_metadata_pointer_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    unpacked: MetadataPointerInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        MetadataPointerInstruction::Initialize { authority, metadata_address } => {
+            let event = _parse_initialize_metadata_pointer_instruction(instruction, context, authority, metadata_address);
+            event.map(|x| Some(Event::InitializeMetadataPointer(x)))
+        },
+        MetadataPointerInstruction::Update { metadata_address } => {
+            let event = _parse_update_metadata_pointer_instruction(instruction, context, metadata_address);
+            event.map(|x| Some(Event::UpdateMetadataPointer(x)))
+        },
+    }
+}
+
+/// Parses an instruction of the Token-2022 GroupPointer extension.
+fn _parse_group_pointer_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    unpacked: GroupPointerInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        GroupPointerInstruction::Initialize { authority, group_address } => {
+            let event = _parse_initialize_group_pointer_instruction(instruction, context, authority, group_address);
+            event.map(|x| Some(Event::InitializeGroupPointer(x)))
+        },
+        GroupPointerInstruction::Update { group_address } => {
+            let event = _parse_update_group_pointer_instruction(instruction, context, group_address);
+            event.map(|x| Some(Event::UpdateGroupPointer(x)))
+        },
+    }
+}
+
+/// Parses an instruction of the token-metadata interface.
+fn _parse_token_metadata_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    unpacked: TokenMetadataInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        TokenMetadataInstruction::Initialize { name, symbol, uri } => {
+            let event = _parse_initialize_token_metadata_instruction(instruction, context, name, symbol, uri);
+            event.map(|x| Some(Event::InitializeTokenMetadata(x)))
+        },
+
+        TokenMetadataInstruction::UpdateField { field, value } => {
+            let event = _parse_update_token_metadata_field_instruction(instruction, context, field, value);
+            event.map(|x| Some(Event::UpdateTokenMetadataField(x)))
+        },
+
+        TokenMetadataInstruction::RemoveKey { idempotent, key } => {
+            let event = _parse_remove_token_metadata_key_instruction(instruction, context, idempotent, key);
+            event.map(|x| Some(Event::RemoveTokenMetadataKey(x)))
+        },
+
+        TokenMetadataInstruction::UpdateAuthority { new_authority } => {
+            let event = _parse_update_token_metadata_authority_instruction(instruction, context, new_authority);
+            event.map(|x| Some(Event::UpdateTokenMetadataAuthority(x)))
+        },
+
+        TokenMetadataInstruction::Emit { start, end } => {
+            let event = _parse_emit_token_metadata_instruction(instruction, context, start, end);
+            event.map(|x| Some(Event::EmitTokenMetadata(x)))
+        },
+    }
+}
+
+/// Parses an instruction of the token-group interface.
+fn _parse_token_group_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+    unpacked: TokenGroupInstruction,
+) -> Result<Option<Event>, ParseError> {
+    match unpacked {
+        TokenGroupInstruction::InitializeGroup { update_authority, max_size } => {
+            let event = _parse_initialize_token_group_instruction(instruction, context, update_authority, max_size);
+            event.map(|x| Some(Event::InitializeTokenGroup(x)))
+        },
+
+        TokenGroupInstruction::UpdateGroupMaxSize { max_size } => {
+            let event = _parse_update_token_group_max_size_instruction(instruction, context, max_size);
+            event.map(|x| Some(Event::UpdateTokenGroupMaxSize(x)))
+        },
+
+        TokenGroupInstruction::UpdateGroupAuthority { new_authority } => {
+            let event = _parse_update_token_group_authority_instruction(instruction, context, new_authority);
+            event.map(|x| Some(Event::UpdateTokenGroupAuthority(x)))
+        },
+
+        TokenGroupInstruction::InitializeMember => {
+            let event = _parse_initialize_token_group_member_instruction(instruction, context);
+            event.map(|x| Some(Event::InitializeTokenGroupMember(x)))
+        },
+    }
 }
 
 fn _parse_initialize_mint_instruction(
@@ -171,8 +852,8 @@ fn _parse_initialize_mint_instruction(
     decimals: u32,
     mint_authority: Pubkey,
     freeze_authority: Option<Pubkey>,
-) -> Result<InitializeMintEvent, &'static str> {
-    let mint = instruction.accounts()[0].to_string();
+) -> Result<InitializeMintEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
     let mint_authority = mint_authority.to_string();
     let freeze_authority = freeze_authority.map(|x| x.to_string());
 
@@ -187,13 +868,29 @@ fn _parse_initialize_mint_instruction(
 fn _parse_initialize_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
-    _owner: Option<Pubkey>,
-) -> Result<InitializeAccountEvent, &'static str> {
-    let address = &instruction.accounts()[0];
-    let token_account = context.get_token_account(address).unwrap();
+    owner: Option<Pubkey>,
+) -> Result<InitializeAccountEvent, ParseError> {
+    let address = get_account(instruction, 0)?;
+    let mint = get_account(instruction, 1)?;
+    // InitializeAccount2 and InitializeAccount3 state the owner instead of passing it.
+    let owner = match owner {
+        Some(owner) => owner.to_string(),
+        None => get_account(instruction, 2)?,
+    };
+    // Balances are only known from the token balance metadata.
+    let (pre_balance, post_balance) = match context.get_token_account(&instruction.accounts()[0]) {
+        Some(token_account) => (token_account.pre_balance, token_account.post_balance),
+        None => (None, None),
+    };
 
     Ok(InitializeAccountEvent {
-        account: Some(token_account.into())
+        account: Some(TokenAccount {
+            address,
+            owner,
+            mint,
+            pre_balance,
+            post_balance,
+        })
     })
 }
 
@@ -202,11 +899,11 @@ fn _parse_initialize_multisig_instruction(
     _context: &TransactionContext,
     m: u8,
     rent_sysvar_account: bool,
-) -> Result<InitializeMultisigEvent, &'static str> {
-    let multisig = instruction.accounts()[0].to_string();
+) -> Result<InitializeMultisigEvent, ParseError> {
+    let multisig = get_account(instruction, 0)?;
     let mut signers: Vec<String> = Vec::new();
     let delta = if rent_sysvar_account { 2 } else { 1 };
-    for account in instruction.accounts()[delta..].iter() {
+    for account in instruction.accounts().iter().skip(delta) {
         signers.push(account.to_string());
     }
 
@@ -220,256 +917,1035 @@ fn _parse_initialize_multisig_instruction(
 fn _parse_transfer_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
     amount: u64,
     expected_decimals: Option<u8>,
-) -> Result<TransferEvent, &'static str> {
+) -> Result<TransferEvent, ParseError> {
     let delta: usize = if expected_decimals.is_none() { 0 } else { 1 };
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let destination = context.get_token_account(&instruction.accounts()[1 + delta]).unwrap();
-    let authority = instruction.accounts()[2 + delta].to_string();
+    let source = get_token_account(instruction, context, registry, 0)?;
+    let destination = get_token_account(instruction, context, registry, 1 + delta)?;
+    let authority = get_account(instruction, 2 + delta)?;
+    let signers = get_signers(instruction, 2 + delta);
+    let authority_role = get_authority_role(&source, &authority, &signers);
 
     Ok(TransferEvent {
-        source: Some(source.into()),
-        destination: Some(destination.into()),
+        source: Some(source),
+        destination: Some(destination),
         amount,
         authority,
+        fee: None,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+        authority_role: authority_role.into(),
     })
 }
 
 fn _parse_approve_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
     amount: u64,
     expected_decimals: Option<u8>,
-) -> Result<ApproveEvent, &'static str> {
+) -> Result<ApproveEvent, ParseError> {
     let delta: usize = if expected_decimals.is_none() { 0 } else { 1 };
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let delegate = instruction.accounts()[1 + delta].to_string();
+    let source = get_token_account(instruction, context, registry, 0)?;
+    let delegate = get_account(instruction, 1 + delta)?;
+    let owner = get_account(instruction, 2 + delta)?;
+    let signers = get_signers(instruction, 2 + delta);
 
     Ok(ApproveEvent {
-        source: Some(source.into()),
+        source: Some(source),
         delegate,
         amount,
+        owner,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
     })
 }
 
 fn _parse_revoke_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
-) -> Result<RevokeEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<RevokeEvent, ParseError> {
+    let source = get_token_account(instruction, context, registry, 0)?;
+    let owner = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
 
     Ok(RevokeEvent {
-        source: Some(source.into()),
+        source: Some(source),
+        owner,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
     })
 }
 
 fn _parse_set_authority_instruction(
     instruction: &StructuredInstruction,
-    _context: &TransactionContext,
-    authority_type: utils::spl_token::AuthorityType,
+    context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
+    authority_type: AuthorityType,
     new_authority: Option<Pubkey>,
-) -> Result<SetAuthorityEvent, &'static str> {
-    let mint = instruction.accounts()[0].to_string();
-    let authority = instruction.accounts()[1].to_string();
-    let authority_type: i32 = match authority_type {
-        utils::spl_token::AuthorityType::MintTokens => AuthorityType::MintTokens.into(),
-        utils::spl_token::AuthorityType::FreezeAccount => AuthorityType::FreezeAccount.into(),
-        utils::spl_token::AuthorityType::AccountOwner => AuthorityType::AccountOwner.into(),
-        utils::spl_token::AuthorityType::CloseAccount => AuthorityType::CloseAccount.into(),
-    };
+) -> Result<SetAuthorityEvent, ParseError> {
+    let authority = get_account(instruction, 1)?;
     let new_authority = new_authority.map(|x| x.to_string());
+    let signers = get_signers(instruction, 1);
+
+    let (mint, target, token_account, previous_authority) = match authority_type {
+        AuthorityType::AccountOwner | AuthorityType::CloseAccount => {
+            let token_account = get_token_account(instruction, context, registry, 0)?;
+            // Without a close authority, the owner signs in its place, so an owner signing may or
+            // may not be the previous close authority.
+            let previous_authority = match authority_type {
+                AuthorityType::CloseAccount if authority == token_account.owner => None,
+                _ => Some(authority.clone()),
+            };
+            (token_account.mint.clone(), AuthorityTarget::TokenAccount, Some(token_account), previous_authority)
+        },
+        _ => (get_account(instruction, 0)?, AuthorityTarget::Mint, None, Some(authority.clone())),
+    };
 
     Ok(SetAuthorityEvent {
         mint,
         authority,
-        authority_type,
+        authority_type: authority_type.into(),
         new_authority,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+        target: target.into(),
+        token_account,
+        previous_authority,
     })
 }
 
 fn _parse_mint_to_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
     amount: u64,
-) -> Result<MintToEvent, &'static str> {
-    let mint = instruction.accounts()[0].to_string();
-    let destination = context.get_token_account(&instruction.accounts()[1]).unwrap();
-    let mint_authority = instruction.accounts()[2].to_string();
+) -> Result<MintToEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let destination = get_token_account(instruction, context, registry, 1)?;
+    let mint_authority = get_account(instruction, 2)?;
+    let signers = get_signers(instruction, 2);
 
     Ok(MintToEvent {
         mint,
-        destination: Some(destination.into()),
+        destination: Some(destination),
         mint_authority,
         amount,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
     })
 }
 
 fn _parse_burn_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
     amount: u64,
-) -> Result<BurnEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let _mint = instruction.accounts()[1].to_string();
-    let authority = instruction.accounts()[2].to_string();
+) -> Result<BurnEvent, ParseError> {
+    let source = get_token_account(instruction, context, registry, 0)?;
+    let mint = get_account(instruction, 1)?;
+    let authority = get_account(instruction, 2)?;
+    let signers = get_signers(instruction, 2);
+    let authority_role = get_authority_role(&source, &authority, &signers);
 
     Ok(BurnEvent {
-        source: Some(source.into()),
+        source: Some(source),
+        mint,
         authority,
         amount,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+        authority_role: authority_role.into(),
     })
 }
 
 fn _parse_close_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
-) -> Result<CloseAccountEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let destination = instruction.accounts()[1].to_string();
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<CloseAccountEvent, ParseError> {
+    // Token-2022 also closes mints with a close authority, which aren't token accounts.
+    let (source, mint) = match get_token_account(instruction, context, registry, 0) {
+        Ok(source) => (Some(source), None),
+        Err(ParseError::UnknownTokenAccount(address)) if instruction.program_id() == TOKEN_2022_PROGRAM_ID => {
+            (None, Some(address))
+        },
+        Err(error) => return Err(error),
+    };
+    let destination = get_account(instruction, 1)?;
+    let authority = get_account(instruction, 2)?;
+    let signers = get_signers(instruction, 2);
 
     Ok(CloseAccountEvent {
-        source: Some(source.into()),
+        source,
         destination,
+        authority,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+        lamports: None,
+        mint,
     })
 }
 
 fn _parse_freeze_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
-) -> Result<FreezeAccountEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let freeze_authority = instruction.accounts()[1].to_string();
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<FreezeAccountEvent, ParseError> {
+    let source = get_token_account(instruction, context, registry, 0)?;
+    let freeze_authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
 
     Ok(FreezeAccountEvent {
-        source: Some(source.into()),
+        source: Some(source),
         freeze_authority,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
     })
 }
 
 fn _parse_thaw_account_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
-) -> Result<ThawAccountEvent, &'static str> {
-    let source = context.get_token_account(&instruction.accounts()[0]).unwrap();
-    let freeze_authority = instruction.accounts()[1].to_string();
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<ThawAccountEvent, ParseError> {
+    let source = get_token_account(instruction, context, registry, 0)?;
+    let freeze_authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
 
     Ok(ThawAccountEvent {
-        source: Some(source.into()),
+        source: Some(source),
         freeze_authority,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
     })
 }
 
 fn _parse_initialize_immutable_owner_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
-) -> Result<InitializeImmutableOwnerEvent, &'static str> {
-    let account = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<InitializeImmutableOwnerEvent, ParseError> {
+    let account = get_token_account(instruction, context, registry, 0)?;
 
     Ok(InitializeImmutableOwnerEvent {
-        account: Some(account.into()),
+        account: Some(account),
     })
 }
 
 fn _parse_sync_native_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
-) -> Result<SyncNativeEvent, &'static str> {
-    let account = context.get_token_account(&instruction.accounts()[0]).unwrap();
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<SyncNativeEvent, ParseError> {
+    let account = get_token_account(instruction, context, registry, 0)?;
 
     Ok(SyncNativeEvent {
-        account: Some(account.into())
+        account: Some(account),
+        lamports: None,
     })
 }
 
-pub fn parse_initialize_mint_instruction<'a>(
-    instruction: &StructuredInstruction<'a>,
-    context: &TransactionContext,
-) -> Result<InitializeMintEvent, &'static str> {
-    match parse_instruction(instruction, context) {
-        Ok(Some(Event::InitializeMint(initialize_mint))) => Ok(initialize_mint),
-        _ => Err("Failed to parse initialize mint instruction."),
-    }
+fn _parse_initialize_transfer_fee_config_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    transfer_fee_config_authority: Option<Pubkey>,
+    withdraw_withheld_authority: Option<Pubkey>,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<InitializeTransferFeeConfigEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializeTransferFeeConfigEvent {
+        mint,
+        transfer_fee_config_authority: transfer_fee_config_authority.map(|x| x.to_string()),
+        withdraw_withheld_authority: withdraw_withheld_authority.map(|x| x.to_string()),
+        transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        maximum_fee,
+    })
 }
 
-pub fn parse_initialize_account_instruction<'a>(
-    instruction: &StructuredInstruction<'a>,
-    context: &TransactionContext,
-) -> Result<InitializeAccountEvent, &'static str> {
-    match parse_instruction(instruction, context) {
-        Ok(Some(Event::InitializeAccount(initialize_account))) => Ok(initialize_account),
-        _ => Err("Failed to parse initialize account instruction."),
-    }
+fn _parse_set_transfer_fee_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<SetTransferFeeEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(SetTransferFeeEvent {
+        mint,
+        authority,
+        transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        maximum_fee,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
 }
 
-pub fn parse_initialize_multisig_instruction<'a>(
-    instruction: &StructuredInstruction<'a>,
+fn _parse_withdraw_withheld_tokens_from_mint_instruction(
+    instruction: &StructuredInstruction,
     context: &TransactionContext,
-) -> Result<InitializeMultisigEvent, &'static str> {
-    match parse_instruction(instruction, context) {
-        Ok(Some(Event::InitializeMultisig(initialize_multisig))) => Ok(initialize_multisig),
-        _ => Err("Failed to parse initialize multisig instruction."),
-    }
+    registry: Option<&TokenAccountRegistry>,
+) -> Result<WithdrawWithheldTokensFromMintEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let destination = get_token_account(instruction, context, registry, 1)?;
+    let authority = get_account(instruction, 2)?;
+    let signers = get_signers(instruction, 2);
+
+    Ok(WithdrawWithheldTokensFromMintEvent {
+        mint,
+        destination: Some(destination),
+        authority,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
 }
 
-
-pub fn parse_transfer_instruction<'a>(
-    instruction: &StructuredInstruction<'a>,
+fn _parse_withdraw_withheld_tokens_from_accounts_instruction(
+    instruction: &StructuredInstruction,
     context: &TransactionContext,
-) -> Result<TransferEvent, &'static str> {
-    match parse_instruction(instruction, context) {
-        Ok(Some(Event::Transfer(transfer))) => Ok(transfer),
-        _ => Err("Failed to parse transfer instruction."),
-    }
+    registry: Option<&TokenAccountRegistry>,
+    num_token_accounts: u8,
+) -> Result<WithdrawWithheldTokensFromAccountsEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let destination = get_token_account(instruction, context, registry, 1)?;
+    let authority = get_account(instruction, 2)?;
+    // The source accounts come last, after the signers of a multisig authority.
+    let accounts = instruction.accounts();
+    let first_source = accounts.len().checked_sub(num_token_accounts as usize)
+        .filter(|x| *x >= 3)
+        .ok_or(ParseError::MissingAccount(accounts.len()))?;
+    let sources = accounts.iter().skip(first_source).map(|x| x.to_string()).collect();
+    let signers: Vec<String> = accounts.iter().take(first_source).skip(3).map(|x| x.to_string()).collect();
+
+    Ok(WithdrawWithheldTokensFromAccountsEvent {
+        mint,
+        destination: Some(destination),
+        authority,
+        sources,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
 }
 
-pub fn parse_approve_instruction<'a>(
+fn _parse_harvest_withheld_tokens_to_mint_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<HarvestWithheldTokensToMintEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let sources = instruction.accounts().iter().skip(1).map(|x| x.to_string()).collect();
+
+    Ok(HarvestWithheldTokensToMintEvent {
+        mint,
+        sources,
+    })
+}
+
+/// Parses an instruction of the Token-2022 ConfidentialTransfer extension. Only the accounts
+/// whose position doesn't depend on how the proofs are supplied are named.
+fn _parse_confidential_transfer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    unpacked: ConfidentialTransferInstruction,
+) -> Result<ConfidentialTransferEvent, ParseError> {
+    use ConfidentialTransferInstructionType as Type;
+
+    let (instruction_type, mint, destination, authority, amount, decimals) = match unpacked {
+        ConfidentialTransferInstruction::InitializeMint => (Type::InitializeMint, None, None, None, None, None),
+        ConfidentialTransferInstruction::UpdateMint => (Type::UpdateMint, None, None, Some(1), None, None),
+        ConfidentialTransferInstruction::ConfigureAccount => (Type::ConfigureAccount, Some(1), None, None, None, None),
+        ConfidentialTransferInstruction::ApproveAccount => (Type::ApproveAccount, Some(1), None, Some(2), None, None),
+        ConfidentialTransferInstruction::EmptyAccount => (Type::EmptyAccount, None, None, None, None, None),
+        ConfidentialTransferInstruction::Deposit { amount, decimals } => (Type::Deposit, Some(1), None, Some(2), Some(amount), Some(decimals)),
+        ConfidentialTransferInstruction::Withdraw { amount, decimals } => (Type::Withdraw, Some(1), None, None, Some(amount), Some(decimals)),
+        ConfidentialTransferInstruction::Transfer => (Type::Transfer, Some(1), Some(2), None, None, None),
+        ConfidentialTransferInstruction::ApplyPendingBalance => (Type::ApplyPendingBalance, None, None, Some(1), None, None),
+        ConfidentialTransferInstruction::EnableConfidentialCredits => (Type::EnableConfidentialCredits, None, None, Some(1), None, None),
+        ConfidentialTransferInstruction::DisableConfidentialCredits => (Type::DisableConfidentialCredits, None, None, Some(1), None, None),
+        ConfidentialTransferInstruction::EnableNonConfidentialCredits => (Type::EnableNonConfidentialCredits, None, None, Some(1), None, None),
+        ConfidentialTransferInstruction::DisableNonConfidentialCredits => (Type::DisableNonConfidentialCredits, None, None, Some(1), None, None),
+        ConfidentialTransferInstruction::TransferWithFee => (Type::TransferWithFee, Some(1), Some(2), None, None, None),
+        ConfidentialTransferInstruction::ConfigureAccountWithRegistry => (Type::ConfigureAccountWithRegistry, Some(1), None, None, None, None),
+    };
+    let account = get_account(instruction, 0)?;
+    let named = [Some(0), mint, destination, authority].into_iter().flatten().max().unwrap_or(0);
+
+    Ok(ConfidentialTransferEvent {
+        instruction_type: instruction_type.into(),
+        account,
+        mint: mint.map(|x| get_account(instruction, x)).transpose()?,
+        destination: destination.map(|x| get_account(instruction, x)).transpose()?,
+        authority: authority.map(|x| get_account(instruction, x)).transpose()?,
+        amount,
+        decimals: decimals.map(|x| x.into()),
+        data: get_extension_data(instruction),
+        remaining_accounts: instruction.accounts().iter().skip(named + 1).map(|x| x.to_string()).collect(),
+    })
+}
+
+/// Parses an instruction of the Token-2022 ConfidentialTransferFee extension.
+fn _parse_confidential_transfer_fee_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    unpacked: ConfidentialTransferFeeInstruction,
+) -> Result<ConfidentialTransferFeeEvent, ParseError> {
+    use ConfidentialTransferFeeInstructionType as Type;
+
+    let mint = get_account(instruction, 0)?;
+    let accounts = instruction.accounts();
+    let mut destination = None;
+    let mut authority = None;
+    let mut sources = Vec::new();
+    let mut named = 1;
+    let instruction_type = match unpacked {
+        ConfidentialTransferFeeInstruction::InitializeConfidentialTransferFeeConfig => Type::InitializeConfidentialTransferFeeConfig,
+        ConfidentialTransferFeeInstruction::WithdrawWithheldTokensFromMint => {
+            destination = Some(get_account(instruction, 1)?);
+            named = 2;
+            Type::WithdrawWithheldTokensFromMint
+        },
+        ConfidentialTransferFeeInstruction::WithdrawWithheldTokensFromAccounts { num_token_accounts } => {
+            destination = Some(get_account(instruction, 1)?);
+            // The source accounts come last, after the proof accounts and the authority signers.
+            let first_source = accounts.len().checked_sub(num_token_accounts as usize)
+                .filter(|x| *x >= 2)
+                .ok_or(ParseError::MissingAccount(accounts.len()))?;
+            sources = accounts.iter().skip(first_source).map(|x| x.to_string()).collect();
+            named = 2;
+            Type::WithdrawWithheldTokensFromAccounts
+        },
+        ConfidentialTransferFeeInstruction::HarvestWithheldTokensToMint => {
+            sources = accounts.iter().skip(1).map(|x| x.to_string()).collect();
+            named = accounts.len();
+            Type::HarvestWithheldTokensToMint
+        },
+        ConfidentialTransferFeeInstruction::EnableHarvestToMint => {
+            authority = Some(get_account(instruction, 1)?);
+            named = 2;
+            Type::EnableHarvestToMint
+        },
+        ConfidentialTransferFeeInstruction::DisableHarvestToMint => {
+            authority = Some(get_account(instruction, 1)?);
+            named = 2;
+            Type::DisableHarvestToMint
+        },
+    };
+    let remaining = accounts.len().saturating_sub(sources.len()).max(named);
+
+    Ok(ConfidentialTransferFeeEvent {
+        instruction_type: instruction_type.into(),
+        mint,
+        destination,
+        authority,
+        sources,
+        data: get_extension_data(instruction),
+        remaining_accounts: accounts.iter().take(remaining).skip(named).map(|x| x.to_string()).collect(),
+    })
+}
+
+fn _parse_initialize_mint_close_authority_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    close_authority: Option<Pubkey>,
+) -> Result<InitializeMintCloseAuthorityEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializeMintCloseAuthorityEvent {
+        mint,
+        close_authority: close_authority.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_initialize_non_transferable_mint_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<InitializeNonTransferableMintEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializeNonTransferableMintEvent {
+        mint,
+    })
+}
+
+fn _parse_initialize_permanent_delegate_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    delegate: Pubkey,
+) -> Result<InitializePermanentDelegateEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializePermanentDelegateEvent {
+        mint,
+        delegate: delegate.to_string(),
+    })
+}
+
+fn _parse_reallocate_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    extension_types: Vec<u16>,
+) -> Result<ReallocateEvent, ParseError> {
+    let account = get_account(instruction, 0)?;
+    let payer = get_account(instruction, 1)?;
+    let owner = get_account(instruction, 3)?;
+    let signers = get_signers(instruction, 3);
+
+    Ok(ReallocateEvent {
+        account,
+        payer,
+        owner,
+        extension_types: extension_types.into_iter().map(extension_type_name).collect(),
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_initialize_default_account_state_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    state: u8,
+) -> Result<InitializeDefaultAccountStateEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializeDefaultAccountStateEvent {
+        mint,
+        state: get_account_state(state)?.into(),
+    })
+}
+
+fn _parse_update_default_account_state_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    state: u8,
+) -> Result<UpdateDefaultAccountStateEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let freeze_authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(UpdateDefaultAccountStateEvent {
+        mint,
+        freeze_authority,
+        state: get_account_state(state)?.into(),
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_memo_transfer_instruction(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
+    enabled: bool,
+) -> Result<MemoTransferEvent, ParseError> {
+    let account = get_token_account(instruction, context, registry, 0)?;
+    let owner = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(MemoTransferEvent {
+        account: Some(account),
+        owner,
+        enabled,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_cpi_guard_instruction(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
+    enabled: bool,
+) -> Result<CpiGuardEvent, ParseError> {
+    let account = get_token_account(instruction, context, registry, 0)?;
+    let owner = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(CpiGuardEvent {
+        account: Some(account),
+        owner,
+        enabled,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_initialize_interest_bearing_mint_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    rate_authority: Option<Pubkey>,
+    rate: i16,
+) -> Result<InitializeInterestBearingMintEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializeInterestBearingMintEvent {
+        mint,
+        rate_authority: rate_authority.map(|x| x.to_string()),
+        rate: rate.into(),
+    })
+}
+
+fn _parse_update_interest_bearing_mint_rate_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    rate: i16,
+) -> Result<UpdateInterestBearingMintRateEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let rate_authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(UpdateInterestBearingMintRateEvent {
+        mint,
+        rate_authority,
+        rate: rate.into(),
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_initialize_transfer_hook_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    authority: Option<Pubkey>,
+    program_id: Option<Pubkey>,
+) -> Result<InitializeTransferHookEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializeTransferHookEvent {
+        mint,
+        authority: authority.map(|x| x.to_string()),
+        program_id: program_id.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_update_transfer_hook_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    program_id: Option<Pubkey>,
+) -> Result<UpdateTransferHookEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(UpdateTransferHookEvent {
+        mint,
+        authority,
+        program_id: program_id.map(|x| x.to_string()),
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_initialize_scaled_ui_amount_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    authority: Option<Pubkey>,
+    multiplier: f64,
+) -> Result<InitializeScaledUiAmountEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializeScaledUiAmountEvent {
+        mint,
+        authority: authority.map(|x| x.to_string()),
+        multiplier,
+    })
+}
+
+fn _parse_update_scaled_ui_amount_multiplier_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    multiplier: f64,
+    effective_timestamp: i64,
+) -> Result<UpdateScaledUiAmountMultiplierEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(UpdateScaledUiAmountMultiplierEvent {
+        mint,
+        authority,
+        multiplier,
+        effective_timestamp,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_initialize_pausable_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    authority: Pubkey,
+) -> Result<InitializePausableEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializePausableEvent {
+        mint,
+        authority: authority.to_string(),
+    })
+}
+
+fn _parse_pause_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<PauseEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(PauseEvent {
+        mint,
+        authority,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_resume_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<ResumeEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(ResumeEvent {
+        mint,
+        authority,
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_initialize_metadata_pointer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    authority: Option<Pubkey>,
+    metadata_address: Option<Pubkey>,
+) -> Result<InitializeMetadataPointerEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializeMetadataPointerEvent {
+        mint,
+        authority: authority.map(|x| x.to_string()),
+        metadata_address: metadata_address.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_update_metadata_pointer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    metadata_address: Option<Pubkey>,
+) -> Result<UpdateMetadataPointerEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(UpdateMetadataPointerEvent {
+        mint,
+        authority,
+        metadata_address: metadata_address.map(|x| x.to_string()),
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_initialize_group_pointer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    authority: Option<Pubkey>,
+    group_address: Option<Pubkey>,
+) -> Result<InitializeGroupPointerEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+
+    Ok(InitializeGroupPointerEvent {
+        mint,
+        authority: authority.map(|x| x.to_string()),
+        group_address: group_address.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_update_group_pointer_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    group_address: Option<Pubkey>,
+) -> Result<UpdateGroupPointerEvent, ParseError> {
+    let mint = get_account(instruction, 0)?;
+    let authority = get_account(instruction, 1)?;
+    let signers = get_signers(instruction, 1);
+
+    Ok(UpdateGroupPointerEvent {
+        mint,
+        authority,
+        group_address: group_address.map(|x| x.to_string()),
+        authority_is_multisig: !signers.is_empty(),
+        signers,
+    })
+}
+
+fn _parse_initialize_token_metadata_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<InitializeTokenMetadataEvent, ParseError> {
+    let metadata = get_account(instruction, 0)?;
+    let update_authority = get_account(instruction, 1)?;
+    let mint = get_account(instruction, 2)?;
+    let mint_authority = get_account(instruction, 3)?;
+
+    Ok(InitializeTokenMetadataEvent {
+        metadata,
+        update_authority,
+        mint,
+        mint_authority,
+        name,
+        symbol,
+        uri,
+    })
+}
+
+fn _parse_update_token_metadata_field_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    field: token_metadata::Field,
+    value: String,
+) -> Result<UpdateTokenMetadataFieldEvent, ParseError> {
+    let metadata = get_account(instruction, 0)?;
+    let update_authority = get_account(instruction, 1)?;
+    let (field, key) = match field {
+        token_metadata::Field::Name => (TokenMetadataField::Name, None),
+        token_metadata::Field::Symbol => (TokenMetadataField::Symbol, None),
+        token_metadata::Field::Uri => (TokenMetadataField::Uri, None),
+        token_metadata::Field::Key(key) => (TokenMetadataField::Key, Some(key)),
+    };
+
+    Ok(UpdateTokenMetadataFieldEvent {
+        metadata,
+        update_authority,
+        field: field.into(),
+        key,
+        value,
+    })
+}
+
+fn _parse_remove_token_metadata_key_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    idempotent: bool,
+    key: String,
+) -> Result<RemoveTokenMetadataKeyEvent, ParseError> {
+    let metadata = get_account(instruction, 0)?;
+    let update_authority = get_account(instruction, 1)?;
+
+    Ok(RemoveTokenMetadataKeyEvent {
+        metadata,
+        update_authority,
+        key,
+        idempotent,
+    })
+}
+
+fn _parse_update_token_metadata_authority_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    new_authority: Option<Pubkey>,
+) -> Result<UpdateTokenMetadataAuthorityEvent, ParseError> {
+    let metadata = get_account(instruction, 0)?;
+    let update_authority = get_account(instruction, 1)?;
+
+    Ok(UpdateTokenMetadataAuthorityEvent {
+        metadata,
+        update_authority,
+        new_authority: new_authority.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_emit_token_metadata_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    start: Option<u64>,
+    end: Option<u64>,
+) -> Result<EmitTokenMetadataEvent, ParseError> {
+    let metadata = get_account(instruction, 0)?;
+
+    Ok(EmitTokenMetadataEvent {
+        metadata,
+        start,
+        end,
+    })
+}
+
+fn _parse_initialize_token_group_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    update_authority: Option<Pubkey>,
+    max_size: u64,
+) -> Result<InitializeTokenGroupEvent, ParseError> {
+    let group = get_account(instruction, 0)?;
+    let mint = get_account(instruction, 1)?;
+    let mint_authority = get_account(instruction, 2)?;
+
+    Ok(InitializeTokenGroupEvent {
+        group,
+        mint,
+        mint_authority,
+        update_authority: update_authority.map(|x| x.to_string()),
+        max_size,
+    })
+}
+
+fn _parse_update_token_group_max_size_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    max_size: u64,
+) -> Result<UpdateTokenGroupMaxSizeEvent, ParseError> {
+    let group = get_account(instruction, 0)?;
+    let update_authority = get_account(instruction, 1)?;
+
+    Ok(UpdateTokenGroupMaxSizeEvent {
+        group,
+        update_authority,
+        max_size,
+    })
+}
+
+fn _parse_update_token_group_authority_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+    new_authority: Option<Pubkey>,
+) -> Result<UpdateTokenGroupAuthorityEvent, ParseError> {
+    let group = get_account(instruction, 0)?;
+    let update_authority = get_account(instruction, 1)?;
+
+    Ok(UpdateTokenGroupAuthorityEvent {
+        group,
+        update_authority,
+        new_authority: new_authority.map(|x| x.to_string()),
+    })
+}
+
+fn _parse_initialize_token_group_member_instruction(
+    instruction: &StructuredInstruction,
+    _context: &TransactionContext,
+) -> Result<InitializeTokenGroupMemberEvent, ParseError> {
+    let member = get_account(instruction, 0)?;
+    let member_mint = get_account(instruction, 1)?;
+    let member_mint_authority = get_account(instruction, 2)?;
+    let group = get_account(instruction, 3)?;
+    let group_update_authority = get_account(instruction, 4)?;
+
+    Ok(InitializeTokenGroupMemberEvent {
+        member,
+        member_mint,
+        member_mint_authority,
+        group,
+        group_update_authority,
+    })
+}
+
+pub fn parse_initialize_mint_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<ApproveEvent, &'static str> {
+) -> Result<InitializeMintEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::InitializeMint(initialize_mint))) => Ok(initialize_mint),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("initialize mint")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_initialize_account_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<InitializeAccountEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::InitializeAccount(initialize_account))) => Ok(initialize_account),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("initialize account")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_initialize_multisig_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<InitializeMultisigEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::InitializeMultisig(initialize_multisig))) => Ok(initialize_multisig),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("initialize multisig")),
+        Err(error) => Err(error),
+    }
+}
+
+
+pub fn parse_transfer_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<TransferEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::Transfer(transfer))) => Ok(transfer),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("transfer")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_approve_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<ApproveEvent, ParseError> {
     match parse_instruction(instruction, context) {
         Ok(Some(Event::Approve(approve))) => Ok(approve),
-        _ => Err("Failed to parse approve instruction."),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("approve")),
+        Err(error) => Err(error),
     }
 }
 
 pub fn parse_revoke_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<RevokeEvent, &'static str> {
+) -> Result<RevokeEvent, ParseError> {
     match parse_instruction(instruction, context) {
         Ok(Some(Event::Revoke(revoke))) => Ok(revoke),
-        _ => Err("Failed to parse revoke instruction."),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("revoke")),
+        Err(error) => Err(error),
     }
 }
 
 pub fn parse_set_authority_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<SetAuthorityEvent, &'static str> {
+) -> Result<SetAuthorityEvent, ParseError> {
     match parse_instruction(instruction, context) {
         Ok(Some(Event::SetAuthority(set_authority))) => Ok(set_authority),
-        _ => Err("Failed to parse set authority instruction."),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("set authority")),
+        Err(error) => Err(error),
     }
 }
 
 pub fn parse_mint_to_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<MintToEvent, &'static str> {
+) -> Result<MintToEvent, ParseError> {
     match parse_instruction(instruction, context) {
         Ok(Some(Event::MintTo(mint_to))) => Ok(mint_to),
-        _ => Err("Failed to parse mint to instruction."),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("mint to")),
+        Err(error) => Err(error),
     }
 }
 
 pub fn parse_burn_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<BurnEvent, &'static str> {
+) -> Result<BurnEvent, ParseError> {
     match parse_instruction(instruction, context) {
         Ok(Some(Event::Burn(burn))) => Ok(burn),
-        _ => Err("Failed to parse burn instruction."),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("burn")),
+        Err(error) => Err(error),
     }
 }
 
@@ -477,51 +1953,212 @@ pub fn parse_burn_instruction<'a>(
 pub fn parse_close_account_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<CloseAccountEvent, &'static str> {
+) -> Result<CloseAccountEvent, ParseError> {
     match parse_instruction(instruction, context) {
         Ok(Some(Event::CloseAccount(close_account))) => Ok(close_account),
-        _ => Err("Failed to parse close account instruction."),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("close account")),
+        Err(error) => Err(error),
     }
 }
 
 pub fn parse_freeze_account_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<FreezeAccountEvent, &'static str> {
+) -> Result<FreezeAccountEvent, ParseError> {
     match parse_instruction(instruction, context) {
         Ok(Some(Event::FreezeAccount(freeze_account))) => Ok(freeze_account),
-        _ => Err("Failed to parse freeze account instruction."),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("freeze account")),
+        Err(error) => Err(error),
     }
 }
 
 pub fn parse_thaw_account_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<ThawAccountEvent, &'static str> {
+) -> Result<ThawAccountEvent, ParseError> {
     match parse_instruction(instruction, context) {
         Ok(Some(Event::ThawAccount(thaw_account))) => Ok(thaw_account),
-        _ => Err("Failed to parse thaw account instruction."),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("thaw account")),
+        Err(error) => Err(error),
     }
 }
 
 pub fn parse_initialize_immutable_owner_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<InitializeImmutableOwnerEvent, &'static str> {
+) -> Result<InitializeImmutableOwnerEvent, ParseError> {
     match parse_instruction(instruction, context) {
         Ok(Some(Event::InitializeImmutableOwner(initialize_immutable_owner))) => Ok(initialize_immutable_owner),
-        _ => Err("Failed to parse initialize immutable owner instruction."),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("initialize immutable owner")),
+        Err(error) => Err(error),
     }
 }
 
-impl<'a> From<&'a utils::spl_token::TokenAccount<'a>> for TokenAccount {
-    fn from(value: &'a utils::spl_token::TokenAccount<'a>) -> Self {
-        Self {
-            address: value.address.to_string(),
-            owner: value.owner.to_string(),
-            mint: value.mint.to_string(),
-            pre_balance: value.pre_balance,
-            post_balance: value.post_balance,
-        }
+pub fn parse_initialize_transfer_fee_config_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<InitializeTransferFeeConfigEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::InitializeTransferFeeConfig(initialize_transfer_fee_config))) => Ok(initialize_transfer_fee_config),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("initialize transfer fee config")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_set_transfer_fee_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<SetTransferFeeEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::SetTransferFee(set_transfer_fee))) => Ok(set_transfer_fee),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("set transfer fee")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_withdraw_withheld_tokens_from_mint_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<WithdrawWithheldTokensFromMintEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::WithdrawWithheldTokensFromMint(withdraw_withheld_tokens_from_mint))) => Ok(withdraw_withheld_tokens_from_mint),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("withdraw withheld tokens from mint")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_withdraw_withheld_tokens_from_accounts_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<WithdrawWithheldTokensFromAccountsEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::WithdrawWithheldTokensFromAccounts(withdraw_withheld_tokens_from_accounts))) => Ok(withdraw_withheld_tokens_from_accounts),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("withdraw withheld tokens from accounts")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_harvest_withheld_tokens_to_mint_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<HarvestWithheldTokensToMintEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::HarvestWithheldTokensToMint(harvest_withheld_tokens_to_mint))) => Ok(harvest_withheld_tokens_to_mint),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("harvest withheld tokens to mint")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_initialize_token_metadata_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<InitializeTokenMetadataEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::InitializeTokenMetadata(initialize_token_metadata))) => Ok(initialize_token_metadata),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("initialize token metadata")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_update_token_metadata_field_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<UpdateTokenMetadataFieldEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::UpdateTokenMetadataField(update_token_metadata_field))) => Ok(update_token_metadata_field),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("update token metadata field")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_initialize_token_group_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<InitializeTokenGroupEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::InitializeTokenGroup(initialize_token_group))) => Ok(initialize_token_group),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("initialize token group")),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn parse_initialize_token_group_member_instruction<'a>(
+    instruction: &StructuredInstruction<'a>,
+    context: &TransactionContext,
+) -> Result<InitializeTokenGroupMemberEvent, ParseError> {
+    match parse_instruction(instruction, context) {
+        Ok(Some(Event::InitializeTokenGroupMember(initialize_token_group_member))) => Ok(initialize_token_group_member),
+        Ok(_) => Err(ParseError::UnexpectedInstruction("initialize token group member")),
+        Err(error) => Err(error),
+    }
+}
+
+fn get_account(instruction: &StructuredInstruction, index: usize) -> Result<String, ParseError> {
+    instruction.accounts().get(index).map(|x| x.to_string()).ok_or(ParseError::MissingAccount(index))
+}
+
+fn get_account_state(state: u8) -> Result<AccountState, ParseError> {
+    AccountState::from_i32(state.into()).ok_or_else(|| ParseError::Unpack(format!("Invalid account state {}", state)))
+}
+
+/// Returns the accounts following the authority at `authority_index`, which are the signers of
+/// a multisig authority. The program rejects any of them that is a signer of the multisig but
+/// didn't sign, whether in the transaction or through `invoke_signed`. Accounts the instruction
+/// hands to the programs it invokes, such as the extra accounts of a Token-2022 transfer hook,
+/// are left out.
+fn get_signers(instruction: &StructuredInstruction, authority_index: usize) -> Vec<String> {
+    let invoked_accounts: Vec<String> = instruction.inner_instructions().iter()
+        .flat_map(|inner| inner.accounts().iter().map(|x| x.to_string()).chain([inner.program_id().to_string()]).collect::<Vec<_>>())
+        .collect();
+    instruction.accounts().iter()
+        .skip(authority_index + 1)
+        .map(|x| x.to_string())
+        .filter(|x| !invoked_accounts.contains(x))
+        .collect()
+}
+
+/// Instruction data of an extension instruction following its extension and instruction types.
+fn get_extension_data(instruction: &StructuredInstruction) -> Vec<u8> {
+    instruction.data().get(2..).unwrap_or_default().to_vec()
+}
+
+/// Role of the authority to the source account, as far as the account itself tells. Delegates
+/// are resolved afterwards by `fill_delegate_roles`.
+fn get_authority_role(source: &TokenAccount, authority: &str, signers: &[String]) -> AuthorityRole {
+    if source.owner != authority {
+        AuthorityRole::UnknownDelegate
+    } else if signers.is_empty() {
+        AuthorityRole::Owner
+    } else {
+        AuthorityRole::Multisig
+    }
+}
+
+/// Gets the token account from the transaction context, or failing that from the registry, in
+/// which case its balances are unknown.
+fn get_token_account(
+    instruction: &StructuredInstruction,
+    context: &TransactionContext,
+    registry: Option<&TokenAccountRegistry>,
+    index: usize,
+) -> Result<TokenAccount, ParseError> {
+    let address = instruction.accounts().get(index).ok_or(ParseError::MissingAccount(index))?;
+    if let Some(token_account) = context.get_token_account(address) {
+        return Ok(token_account.into());
+    }
+    let address = address.to_string();
+    // A closed account can't be used until it's initialized again.
+    let record = registry
+        .and_then(|x| x.get_last(&address))
+        .filter(|x| x.state != AccountState::Uninitialized as i32);
+    match record {
+        Some(record) => Ok(TokenAccount {
+            address,
+            owner: record.owner,
+            mint: record.mint,
+            pre_balance: None,
+            post_balance: None,
+        }),
+        None => Err(ParseError::UnknownTokenAccount(address)),
     }
 }
@@ -1,28 +1,49 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SplTokenBlockEvents {
     #[prost(message, repeated, tag="1")]
     pub transactions: ::prost::alloc::vec::Vec<SplTokenTransactionEvents>,
+    #[prost(message, optional, tag="2")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::BlockEnvelope>,
+    /// Instructions that failed to parse. Only populated with `error_policy=report`.
+    #[prost(message, repeated, tag="3")]
+    pub parse_failures: ::prost::alloc::vec::Vec<::solana_substreams_common::pb::solana_common::ParseFailure>,
+    #[prost(uint32, tag="4")]
+    pub parse_failure_count: u32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SplTokenTransactionEvents {
     #[prost(string, tag="1")]
     pub signature: ::prost::alloc::string::String,
     #[prost(message, repeated, tag="3")]
     pub events: ::prost::alloc::vec::Vec<SplTokenEvent>,
+    #[prost(message, optional, tag="4")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionEnvelope>,
+    /// Set on failed transactions, which are only included when `include_failed_transactions` is enabled.
+    #[prost(message, optional, tag="5")]
+    pub error: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionError>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SplTokenEvent {
-    #[prost(oneof="spl_token_event::Event", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14")]
+    #[prost(message, optional, tag="15")]
+    pub position: ::core::option::Option<::solana_substreams_common::pb::solana_common::InstructionPosition>,
+    /// Program that emitted the event, either the Token program or Token-2022.
+    #[prost(string, tag="16")]
+    pub token_program: ::prost::alloc::string::String,
+    #[prost(oneof="spl_token_event::Event", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57")]
     pub event: ::core::option::Option<spl_token_event::Event>,
 }
 /// Nested message and enum types in `SplTokenEvent`.
 pub mod spl_token_event {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
     pub enum Event {
         #[prost(message, tag="1")]
         Transfer(super::TransferEvent),
@@ -52,10 +73,93 @@ pub mod spl_token_event {
         ThawAccount(super::ThawAccountEvent),
         #[prost(message, tag="14")]
         SyncNative(super::SyncNativeEvent),
+        #[prost(message, tag="17")]
+        InitializeTransferFeeConfig(super::InitializeTransferFeeConfigEvent),
+        #[prost(message, tag="18")]
+        SetTransferFee(super::SetTransferFeeEvent),
+        #[prost(message, tag="19")]
+        WithdrawWithheldTokensFromMint(super::WithdrawWithheldTokensFromMintEvent),
+        #[prost(message, tag="20")]
+        WithdrawWithheldTokensFromAccounts(super::WithdrawWithheldTokensFromAccountsEvent),
+        #[prost(message, tag="21")]
+        HarvestWithheldTokensToMint(super::HarvestWithheldTokensToMintEvent),
+        #[prost(message, tag="22")]
+        InitializeTokenMetadata(super::InitializeTokenMetadataEvent),
+        #[prost(message, tag="23")]
+        UpdateTokenMetadataField(super::UpdateTokenMetadataFieldEvent),
+        #[prost(message, tag="24")]
+        RemoveTokenMetadataKey(super::RemoveTokenMetadataKeyEvent),
+        #[prost(message, tag="25")]
+        UpdateTokenMetadataAuthority(super::UpdateTokenMetadataAuthorityEvent),
+        #[prost(message, tag="26")]
+        EmitTokenMetadata(super::EmitTokenMetadataEvent),
+        #[prost(message, tag="27")]
+        InitializeTokenGroup(super::InitializeTokenGroupEvent),
+        #[prost(message, tag="28")]
+        UpdateTokenGroupMaxSize(super::UpdateTokenGroupMaxSizeEvent),
+        #[prost(message, tag="29")]
+        UpdateTokenGroupAuthority(super::UpdateTokenGroupAuthorityEvent),
+        #[prost(message, tag="30")]
+        InitializeTokenGroupMember(super::InitializeTokenGroupMemberEvent),
+        #[prost(message, tag="31")]
+        InitializeMetadataPointer(super::InitializeMetadataPointerEvent),
+        #[prost(message, tag="32")]
+        UpdateMetadataPointer(super::UpdateMetadataPointerEvent),
+        #[prost(message, tag="33")]
+        InitializeGroupPointer(super::InitializeGroupPointerEvent),
+        #[prost(message, tag="34")]
+        UpdateGroupPointer(super::UpdateGroupPointerEvent),
+        #[prost(message, tag="35")]
+        ConfidentialTransfer(super::ConfidentialTransferEvent),
+        #[prost(message, tag="36")]
+        ConfidentialTransferFee(super::ConfidentialTransferFeeEvent),
+        #[prost(message, tag="37")]
+        InitializeMintCloseAuthority(super::InitializeMintCloseAuthorityEvent),
+        #[prost(message, tag="38")]
+        InitializePermanentDelegate(super::InitializePermanentDelegateEvent),
+        #[prost(message, tag="39")]
+        InitializeDefaultAccountState(super::InitializeDefaultAccountStateEvent),
+        #[prost(message, tag="40")]
+        UpdateDefaultAccountState(super::UpdateDefaultAccountStateEvent),
+        #[prost(message, tag="41")]
+        CpiGuard(super::CpiGuardEvent),
+        #[prost(message, tag="42")]
+        MemoTransfer(super::MemoTransferEvent),
+        #[prost(message, tag="43")]
+        InitializeInterestBearingMint(super::InitializeInterestBearingMintEvent),
+        #[prost(message, tag="44")]
+        UpdateInterestBearingMintRate(super::UpdateInterestBearingMintRateEvent),
+        #[prost(message, tag="45")]
+        InitializeNonTransferableMint(super::InitializeNonTransferableMintEvent),
+        #[prost(message, tag="46")]
+        InitializeTransferHook(super::InitializeTransferHookEvent),
+        #[prost(message, tag="47")]
+        UpdateTransferHook(super::UpdateTransferHookEvent),
+        #[prost(message, tag="48")]
+        Reallocate(super::ReallocateEvent),
+        #[prost(message, tag="49")]
+        InitializeScaledUiAmount(super::InitializeScaledUiAmountEvent),
+        #[prost(message, tag="50")]
+        UpdateScaledUiAmountMultiplier(super::UpdateScaledUiAmountMultiplierEvent),
+        #[prost(message, tag="51")]
+        InitializePausable(super::InitializePausableEvent),
+        #[prost(message, tag="52")]
+        Pause(super::PauseEvent),
+        #[prost(message, tag="53")]
+        Resume(super::ResumeEvent),
+        #[prost(message, tag="54")]
+        WrappedSol(super::WrappedSolEvent),
+        #[prost(message, tag="55")]
+        AmountToUiAmount(super::AmountToUiAmountEvent),
+        #[prost(message, tag="56")]
+        UiAmountToAmount(super::UiAmountToAmountEvent),
+        #[prost(message, tag="57")]
+        GetAccountDataSize(super::GetAccountDataSizeEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct InitializeMintEvent {
     #[prost(string, tag="1")]
     pub mint: ::prost::alloc::string::String,
@@ -68,12 +172,14 @@ pub struct InitializeMintEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct InitializeAccountEvent {
     #[prost(message, optional, tag="1")]
-    pub account: ::core::option::Option<TokenAccount>,
+    pub account: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct InitializeMultisigEvent {
     #[prost(string, tag="1")]
     pub multisig: ::prost::alloc::string::String,
@@ -84,121 +190,914 @@ pub struct InitializeMultisigEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct TransferEvent {
     #[prost(message, optional, tag="1")]
-    pub source: ::core::option::Option<TokenAccount>,
+    pub source: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
     #[prost(message, optional, tag="2")]
-    pub destination: ::core::option::Option<TokenAccount>,
+    pub destination: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
     #[prost(string, tag="3")]
     pub authority: ::prost::alloc::string::String,
-    /// optional uint64 source_pre_balance = 5;
-    /// optional uint64 destination_pre_balance = 6;
+    /// Gross amount debited from the source.
     #[prost(uint64, tag="4")]
     pub amount: u64,
+    /// Fee withheld in the destination, on Token-2022 mints with a transfer fee. The destination
+    /// receives `amount - fee`. Unset when the fee can't be determined.
+    #[prost(uint64, optional, tag="5")]
+    pub fee: ::core::option::Option<u64>,
+    /// Co-signers when the authority is a multisig account: the accounts following the authority,
+    /// less the extra accounts of a transfer hook.
+    #[prost(string, repeated, tag="6")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="7")]
+    pub authority_is_multisig: bool,
+    /// Who the authority is to the source account.
+    #[prost(enumeration="AuthorityRole", tag="8")]
+    pub authority_role: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct ApproveEvent {
     #[prost(message, optional, tag="1")]
-    pub source: ::core::option::Option<TokenAccount>,
+    pub source: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
     #[prost(string, tag="2")]
     pub delegate: ::prost::alloc::string::String,
     #[prost(uint64, tag="3")]
     pub amount: u64,
+    #[prost(string, tag="4")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="5")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="6")]
+    pub authority_is_multisig: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct RevokeEvent {
     #[prost(message, optional, tag="1")]
-    pub source: ::core::option::Option<TokenAccount>,
+    pub source: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="3")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="4")]
+    pub authority_is_multisig: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SetAuthorityEvent {
+    /// Mint the authority belongs to, or the mint of the token account when the target is a token account.
     #[prost(string, tag="1")]
     pub mint: ::prost::alloc::string::String,
+    /// Account that signed the change: the current authority, or the owner of the token account.
     #[prost(string, tag="2")]
     pub authority: ::prost::alloc::string::String,
     #[prost(enumeration="AuthorityType", tag="3")]
     pub authority_type: i32,
     #[prost(string, optional, tag="4")]
     pub new_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="5")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="6")]
+    pub authority_is_multisig: bool,
+    /// Kind of account whose authority is set: a token account for `AccountOwner` and
+    /// `CloseAccount`, a mint otherwise.
+    #[prost(enumeration="AuthorityTarget", tag="7")]
+    pub target: i32,
+    /// Set when the target is a token account.
+    #[prost(message, optional, tag="8")]
+    pub token_account: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
+    /// Authority being replaced. Unknown, and unset, for a `CloseAccount` authority set by the
+    /// owner: the owner signs for an account without a close authority, but may also be its close
+    /// authority.
+    #[prost(string, optional, tag="9")]
+    pub previous_authority: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MintToEvent {
     #[prost(string, tag="1")]
     pub mint: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub mint_authority: ::prost::alloc::string::String,
     #[prost(message, optional, tag="3")]
-    pub destination: ::core::option::Option<TokenAccount>,
+    pub destination: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
     #[prost(uint64, tag="4")]
     pub amount: u64,
+    #[prost(string, repeated, tag="5")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="6")]
+    pub authority_is_multisig: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct BurnEvent {
     #[prost(message, optional, tag="1")]
-    pub source: ::core::option::Option<TokenAccount>,
+    pub source: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
     #[prost(string, tag="3")]
     pub authority: ::prost::alloc::string::String,
     #[prost(uint64, tag="2")]
     pub amount: u64,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+    #[prost(enumeration="AuthorityRole", tag="6")]
+    pub authority_role: i32,
+    #[prost(string, tag="7")]
+    pub mint: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CloseAccountEvent {
+    /// Closed token account. Unset when a Token-2022 mint is closed.
     #[prost(message, optional, tag="1")]
-    pub source: ::core::option::Option<TokenAccount>,
-    /// TODO: amount
+    pub source: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
     #[prost(string, tag="2")]
     pub destination: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+    /// Lamports refunded to `destination`, including the rent-exempt reserve. Unset on failed
+    /// transactions.
+    #[prost(uint64, optional, tag="6")]
+    pub lamports: ::core::option::Option<u64>,
+    /// Closed mint, when a Token-2022 mint with a close authority is closed.
+    #[prost(string, optional, tag="7")]
+    pub mint: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct FreezeAccountEvent {
     #[prost(message, optional, tag="1")]
-    pub source: ::core::option::Option<TokenAccount>,
+    pub source: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
     #[prost(string, tag="2")]
     pub freeze_authority: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="3")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="4")]
+    pub authority_is_multisig: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct ThawAccountEvent {
     #[prost(message, optional, tag="1")]
-    pub source: ::core::option::Option<TokenAccount>,
+    pub source: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
     #[prost(string, tag="2")]
     pub freeze_authority: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="3")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="4")]
+    pub authority_is_multisig: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct InitializeImmutableOwnerEvent {
     #[prost(message, optional, tag="1")]
-    pub account: ::core::option::Option<TokenAccount>,
+    pub account: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SyncNativeEvent {
-    /// TODO: amount
     #[prost(message, optional, tag="1")]
-    pub account: ::core::option::Option<TokenAccount>,
+    pub account: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
+    /// Lamports added to the token amount, i.e. the SOL wrapped since the last sync. Unset on
+    /// failed transactions.
+    #[prost(uint64, optional, tag="2")]
+    pub lamports: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeTransferFeeConfigEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub transfer_fee_config_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub withdraw_withheld_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag="4")]
+    pub transfer_fee_basis_points: u32,
+    #[prost(uint64, tag="5")]
+    pub maximum_fee: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TokenAccount {
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct SetTransferFeeEvent {
     #[prost(string, tag="1")]
-    pub address: ::prost::alloc::string::String,
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(uint32, tag="3")]
+    pub transfer_fee_basis_points: u32,
+    #[prost(uint64, tag="4")]
+    pub maximum_fee: u64,
+    #[prost(string, repeated, tag="5")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="6")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct WithdrawWithheldTokensFromMintEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub destination: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct WithdrawWithheldTokensFromAccountsEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub destination: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
+    #[prost(string, tag="3")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="4")]
+    pub sources: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="5")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="6")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct HarvestWithheldTokensToMintEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="2")]
+    pub sources: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeTokenMetadataEvent {
+    /// Account holding the metadata, usually the mint itself.
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub uri: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateTokenMetadataFieldEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(enumeration="TokenMetadataField", tag="3")]
+    pub field: i32,
+    /// Set when `field` is `Key`.
+    #[prost(string, optional, tag="4")]
+    pub key: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="5")]
+    pub value: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct RemoveTokenMetadataKeyEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(bool, tag="4")]
+    pub idempotent: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateTokenMetadataAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    /// Unset when the metadata is made immutable.
+    #[prost(string, optional, tag="3")]
+    pub new_authority: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct EmitTokenMetadataEvent {
+    #[prost(string, tag="1")]
+    pub metadata: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="2")]
+    pub start: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="3")]
+    pub end: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeTokenGroupEvent {
+    #[prost(string, tag="1")]
+    pub group: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint_authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub update_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="5")]
+    pub max_size: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateTokenGroupMaxSizeEvent {
+    #[prost(string, tag="1")]
+    pub group: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub max_size: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateTokenGroupAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub group: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub update_authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub new_authority: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeTokenGroupMemberEvent {
+    #[prost(string, tag="1")]
+    pub member: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub member_mint: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub member_mint_authority: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub group: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub group_update_authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeMetadataPointerEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub metadata_address: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateMetadataPointerEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub metadata_address: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeGroupPointerEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub group_address: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateGroupPointerEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub group_address: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+}
+/// An instruction of the ConfidentialTransfer extension. Encrypted balances and amounts, and
+/// the zero-knowledge proofs that go with them, are not decoded.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct ConfidentialTransferEvent {
+    #[prost(enumeration="ConfidentialTransferInstructionType", tag="1")]
+    pub instruction_type: i32,
+    /// Token account the instruction applies to, the source for transfers, or the mint for
+    /// `InitializeMint` and `UpdateMint`.
+    #[prost(string, tag="2")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub mint: ::core::option::Option<::prost::alloc::string::String>,
+    /// Destination token account of `Transfer` and `TransferWithFee`.
+    #[prost(string, optional, tag="4")]
+    pub destination: ::core::option::Option<::prost::alloc::string::String>,
+    /// Owner of the account, or the mint's confidential transfer authority, when its position is fixed.
+    #[prost(string, optional, tag="5")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    /// Plaintext amount of `Deposit` and `Withdraw`.
+    #[prost(uint64, optional, tag="6")]
+    pub amount: ::core::option::Option<u64>,
+    #[prost(uint32, optional, tag="7")]
+    pub decimals: ::core::option::Option<u32>,
+    /// Instruction data following the instruction type, holding ciphertexts and proof instruction offsets.
+    #[prost(bytes="vec", tag="8")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// Accounts following the named ones, e.g. proof context state accounts, sysvars and multisig signers.
+    #[prost(string, repeated, tag="9")]
+    pub remaining_accounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// An instruction of the ConfidentialTransferFee extension.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct ConfidentialTransferFeeEvent {
+    #[prost(enumeration="ConfidentialTransferFeeInstructionType", tag="1")]
+    pub instruction_type: i32,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    /// Token account receiving the withdrawn fees.
+    #[prost(string, optional, tag="3")]
+    pub destination: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="4")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    /// Token accounts the withheld fees are withdrawn or harvested from.
+    #[prost(string, repeated, tag="5")]
+    pub sources: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Instruction data following the instruction type, holding ciphertexts and proof instruction offsets.
+    #[prost(bytes="vec", tag="6")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// Accounts following the named ones, e.g. proof context state accounts, sysvars and multisig signers.
+    #[prost(string, repeated, tag="7")]
+    pub remaining_accounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeMintCloseAuthorityEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub close_authority: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializePermanentDelegateEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    /// Can transfer or burn tokens from any account of the mint.
+    #[prost(string, tag="2")]
+    pub delegate: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeDefaultAccountStateEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(enumeration="AccountState", tag="2")]
+    pub state: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateDefaultAccountStateEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub freeze_authority: ::prost::alloc::string::String,
+    #[prost(enumeration="AccountState", tag="3")]
+    pub state: i32,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct CpiGuardEvent {
+    #[prost(message, optional, tag="1")]
+    pub account: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
+    #[prost(string, tag="2")]
+    pub owner: ::prost::alloc::string::String,
+    /// Whether the guard was enabled or disabled.
+    #[prost(bool, tag="3")]
+    pub enabled: bool,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct MemoTransferEvent {
+    #[prost(message, optional, tag="1")]
+    pub account: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
     #[prost(string, tag="2")]
     pub owner: ::prost::alloc::string::String,
+    /// Whether incoming transfers now require a memo.
+    #[prost(bool, tag="3")]
+    pub enabled: bool,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeInterestBearingMintEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub rate_authority: ::core::option::Option<::prost::alloc::string::String>,
+    /// Interest rate in basis points.
+    #[prost(int32, tag="3")]
+    pub rate: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateInterestBearingMintRateEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub rate_authority: ::prost::alloc::string::String,
+    #[prost(int32, tag="3")]
+    pub rate: i32,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeNonTransferableMintEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeTransferHookEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    /// Program invoked on every transfer of the mint.
+    #[prost(string, optional, tag="3")]
+    pub program_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateTransferHookEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub program_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="4")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="5")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct ReallocateEvent {
+    #[prost(string, tag="1")]
+    pub account: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub payer: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
+    pub owner: ::prost::alloc::string::String,
+    /// Names of the extension types made room for, e.g. `MemoTransfer`.
+    #[prost(string, repeated, tag="4")]
+    pub extension_types: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="5")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="6")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializeScaledUiAmountEvent {
+    #[prost(string, tag="1")]
     pub mint: ::prost::alloc::string::String,
-    #[prost(uint64, optional, tag="4")]
-    pub pre_balance: ::core::option::Option<u64>,
-    #[prost(uint64, optional, tag="5")]
-    pub post_balance: ::core::option::Option<u64>,
+    #[prost(string, optional, tag="2")]
+    pub authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(double, tag="3")]
+    pub multiplier: f64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UpdateScaledUiAmountMultiplierEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(double, tag="3")]
+    pub multiplier: f64,
+    /// Unix timestamp from which the new multiplier applies.
+    #[prost(int64, tag="4")]
+    pub effective_timestamp: i64,
+    #[prost(string, repeated, tag="5")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="6")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct InitializePausableEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct PauseEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="3")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="4")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct ResumeEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub authority: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="3")]
+    pub signers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag="4")]
+    pub authority_is_multisig: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct AmountToUiAmountEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub amount: u64,
+    #[prost(string, optional, tag="3")]
+    pub ui_amount: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct UiAmountToAmountEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub ui_amount: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="3")]
+    pub amount: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct GetAccountDataSizeEvent {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    /// Names of the Token-2022 extension types requested for the account.
+    #[prost(string, repeated, tag="2")]
+    pub extension_types: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag="3")]
+    pub data_size: ::core::option::Option<u64>,
+}
+/// SOL wrapped into or unwrapped from a native token account. Derived from a `SyncNative` and the
+/// System transfers into the account before it, or from the `CloseAccount` of a native account,
+/// and emitted right after that instruction's event.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct WrappedSolEvent {
+    #[prost(enumeration="WrappedSolDirection", tag="1")]
+    pub direction: i32,
+    #[prost(message, optional, tag="2")]
+    pub account: ::core::option::Option<::solana_substreams_common::pb::solana_common::TokenAccount>,
+    /// Lamports wrapped by the sync, or refunded by the close including the rent-exempt reserve.
+    #[prost(uint64, tag="3")]
+    pub lamports: u64,
+    /// Accounts that transferred the wrapped lamports.
+    #[prost(string, repeated, tag="4")]
+    pub funding_accounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Account receiving the unwrapped lamports.
+    #[prost(string, optional, tag="5")]
+    pub destination: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Token account kept by `store_token_account_registry`, keyed by its address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct TokenAccountRecord {
+    #[prost(string, tag="1")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    /// `Uninitialized` once the account is closed.
+    #[prost(enumeration="AccountState", tag="3")]
+    pub state: i32,
+}
+/// Delegate of a token account kept by `store_delegates`, keyed by the token account's address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct DelegateRecord {
+    #[prost(string, tag="1")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub mint: ::prost::alloc::string::String,
+    /// Unset once the approval is revoked, or reset by a change of owner or the account's closing.
+    #[prost(string, optional, tag="3")]
+    pub delegate: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Changes of the allowances of token accounts in a block, output by `allowance_changes`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct AllowanceChanges {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(int64, optional, tag="2")]
+    pub block_time: ::core::option::Option<i64>,
+    #[prost(message, repeated, tag="3")]
+    pub changes: ::prost::alloc::vec::Vec<AllowanceChange>,
+}
+/// Amount the delegate of a token account may still transfer or burn, before and after an
+/// `Approve`, a spend by the delegate, or a reset of the approval. Amounts are decimal strings.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct AllowanceChange {
+    /// Owner of the token account, by which allowances are meant to be keyed.
+    #[prost(string, tag="1")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub token_account: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub delegate: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="5")]
+    pub old_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub new_amount: ::prost::alloc::string::String,
+}
+/// Changes of the mint and freeze authorities of mints in a block, output by `mint_authority_changes`.
+/// Only successful transactions are considered.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct MintAuthorityChanges {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(int64, optional, tag="2")]
+    pub block_time: ::core::option::Option<i64>,
+    #[prost(message, repeated, tag="3")]
+    pub changes: ::prost::alloc::vec::Vec<MintAuthorityChange>,
+}
+/// Change of the mint or freeze authority of a mint. An unset authority means the mint has none.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct MintAuthorityChange {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    /// `MintTokens` or `FreezeAccount`.
+    #[prost(enumeration="AuthorityType", tag="2")]
+    pub authority_type: i32,
+    #[prost(string, optional, tag="3")]
+    pub old_authority: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="4")]
+    pub new_authority: ::core::option::Option<::prost::alloc::string::String>,
+    /// Whether the authority was set to none, which can't be undone.
+    #[prost(bool, tag="5")]
+    pub revoked: bool,
+}
+/// Changes of the supply of mints in a block, output by `mint_supply_deltas`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct MintSupplyDeltas {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(int64, optional, tag="2")]
+    pub block_time: ::core::option::Option<i64>,
+    #[prost(message, repeated, tag="3")]
+    pub deltas: ::prost::alloc::vec::Vec<MintSupplyDelta>,
+}
+/// Supply of a mint before and after a `MintTo` or `Burn`, or its initial supply. Supplies are
+/// decimal strings. For a mint initialized before the start block, they are relative to its supply
+/// at the start block, and can be negative.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct MintSupplyDelta {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub old_supply: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub new_supply: ::prost::alloc::string::String,
+}
+/// Changes of the holder count of mints in a block, output by `mint_holder_count_deltas`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct MintHolderCountDeltas {
+    #[prost(uint64, tag="1")]
+    pub slot: u64,
+    #[prost(int64, optional, tag="2")]
+    pub block_time: ::core::option::Option<i64>,
+    #[prost(message, repeated, tag="3")]
+    pub deltas: ::prost::alloc::vec::Vec<MintHolderCountDelta>,
+}
+/// Number of owners with a positive balance of a mint, before and after one of them crossed zero.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+pub struct MintHolderCountDelta {
+    #[prost(string, tag="1")]
+    pub mint: ::prost::alloc::string::String,
+    #[prost(int64, tag="2")]
+    pub old_holder_count: i64,
+    #[prost(int64, tag="3")]
+    pub new_holder_count: i64,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 #[repr(i32)]
 pub enum AuthorityType {
     Null = 0,
@@ -206,6 +1105,20 @@ pub enum AuthorityType {
     FreezeAccount = 2,
     AccountOwner = 3,
     CloseAccount = 4,
+    /// Token-2022 authority types.
+    TransferFeeConfig = 5,
+    WithheldWithdraw = 6,
+    CloseMint = 7,
+    InterestRate = 8,
+    PermanentDelegate = 9,
+    ConfidentialTransferMint = 10,
+    TransferHookProgramId = 11,
+    ConfidentialTransferFeeConfig = 12,
+    MetadataPointer = 13,
+    GroupPointer = 14,
+    GroupMemberPointer = 15,
+    ScaledUiAmount = 16,
+    Pause = 17,
 }
 impl AuthorityType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -219,6 +1132,19 @@ impl AuthorityType {
             AuthorityType::FreezeAccount => "FreezeAccount",
             AuthorityType::AccountOwner => "AccountOwner",
             AuthorityType::CloseAccount => "CloseAccount",
+            AuthorityType::TransferFeeConfig => "TransferFeeConfig",
+            AuthorityType::WithheldWithdraw => "WithheldWithdraw",
+            AuthorityType::CloseMint => "CloseMint",
+            AuthorityType::InterestRate => "InterestRate",
+            AuthorityType::PermanentDelegate => "PermanentDelegate",
+            AuthorityType::ConfidentialTransferMint => "ConfidentialTransferMint",
+            AuthorityType::TransferHookProgramId => "TransferHookProgramId",
+            AuthorityType::ConfidentialTransferFeeConfig => "ConfidentialTransferFeeConfig",
+            AuthorityType::MetadataPointer => "MetadataPointer",
+            AuthorityType::GroupPointer => "GroupPointer",
+            AuthorityType::GroupMemberPointer => "GroupMemberPointer",
+            AuthorityType::ScaledUiAmount => "ScaledUiAmount",
+            AuthorityType::Pause => "Pause",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -229,6 +1155,288 @@ impl AuthorityType {
             "FreezeAccount" => Some(Self::FreezeAccount),
             "AccountOwner" => Some(Self::AccountOwner),
             "CloseAccount" => Some(Self::CloseAccount),
+            "TransferFeeConfig" => Some(Self::TransferFeeConfig),
+            "WithheldWithdraw" => Some(Self::WithheldWithdraw),
+            "CloseMint" => Some(Self::CloseMint),
+            "InterestRate" => Some(Self::InterestRate),
+            "PermanentDelegate" => Some(Self::PermanentDelegate),
+            "ConfidentialTransferMint" => Some(Self::ConfidentialTransferMint),
+            "TransferHookProgramId" => Some(Self::TransferHookProgramId),
+            "ConfidentialTransferFeeConfig" => Some(Self::ConfidentialTransferFeeConfig),
+            "MetadataPointer" => Some(Self::MetadataPointer),
+            "GroupPointer" => Some(Self::GroupPointer),
+            "GroupMemberPointer" => Some(Self::GroupMemberPointer),
+            "ScaledUiAmount" => Some(Self::ScaledUiAmount),
+            "Pause" => Some(Self::Pause),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+#[repr(i32)]
+pub enum AuthorityTarget {
+    Mint = 0,
+    TokenAccount = 1,
+}
+impl AuthorityTarget {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AuthorityTarget::Mint => "Mint",
+            AuthorityTarget::TokenAccount => "TokenAccount",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Mint" => Some(Self::Mint),
+            "TokenAccount" => Some(Self::TokenAccount),
+            _ => None,
+        }
+    }
+}
+/// Who the authority of a transfer or burn is to the source account. Delegates are only known
+/// from the `Approve` and permanent delegate events earlier in the same transaction, so any other
+/// authority is an `UnknownDelegate`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+#[repr(i32)]
+pub enum AuthorityRole {
+    Unspecified = 0,
+    UnknownDelegate = 1,
+    /// The owner of the source account.
+    Owner = 2,
+    /// The delegate approved for the source account.
+    Delegate = 3,
+    /// The owner of the source account, being a multisig account.
+    Multisig = 4,
+    /// The Token-2022 permanent delegate of the mint.
+    PermanentDelegate = 5,
+}
+impl AuthorityRole {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AuthorityRole::Unspecified => "Unspecified",
+            AuthorityRole::UnknownDelegate => "UnknownDelegate",
+            AuthorityRole::Owner => "Owner",
+            AuthorityRole::Delegate => "Delegate",
+            AuthorityRole::Multisig => "Multisig",
+            AuthorityRole::PermanentDelegate => "PermanentDelegate",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Unspecified" => Some(Self::Unspecified),
+            "UnknownDelegate" => Some(Self::UnknownDelegate),
+            "Owner" => Some(Self::Owner),
+            "Delegate" => Some(Self::Delegate),
+            "Multisig" => Some(Self::Multisig),
+            "PermanentDelegate" => Some(Self::PermanentDelegate),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+#[repr(i32)]
+pub enum TokenMetadataField {
+    Name = 0,
+    Symbol = 1,
+    Uri = 2,
+    /// A custom field, in the additional metadata.
+    Key = 3,
+}
+impl TokenMetadataField {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TokenMetadataField::Name => "Name",
+            TokenMetadataField::Symbol => "Symbol",
+            TokenMetadataField::Uri => "Uri",
+            TokenMetadataField::Key => "Key",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Name" => Some(Self::Name),
+            "Symbol" => Some(Self::Symbol),
+            "Uri" => Some(Self::Uri),
+            "Key" => Some(Self::Key),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+#[repr(i32)]
+pub enum AccountState {
+    Uninitialized = 0,
+    Initialized = 1,
+    Frozen = 2,
+}
+impl AccountState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AccountState::Uninitialized => "Uninitialized",
+            AccountState::Initialized => "Initialized",
+            AccountState::Frozen => "Frozen",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Uninitialized" => Some(Self::Uninitialized),
+            "Initialized" => Some(Self::Initialized),
+            "Frozen" => Some(Self::Frozen),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+#[repr(i32)]
+pub enum WrappedSolDirection {
+    Wrap = 0,
+    Unwrap = 1,
+}
+impl WrappedSolDirection {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            WrappedSolDirection::Wrap => "Wrap",
+            WrappedSolDirection::Unwrap => "Unwrap",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Wrap" => Some(Self::Wrap),
+            "Unwrap" => Some(Self::Unwrap),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+#[repr(i32)]
+pub enum ConfidentialTransferInstructionType {
+    InitializeMint = 0,
+    UpdateMint = 1,
+    ConfigureAccount = 2,
+    ApproveAccount = 3,
+    EmptyAccount = 4,
+    Deposit = 5,
+    Withdraw = 6,
+    Transfer = 7,
+    ApplyPendingBalance = 8,
+    EnableConfidentialCredits = 9,
+    DisableConfidentialCredits = 10,
+    EnableNonConfidentialCredits = 11,
+    DisableNonConfidentialCredits = 12,
+    TransferWithFee = 13,
+    ConfigureAccountWithRegistry = 14,
+}
+impl ConfidentialTransferInstructionType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ConfidentialTransferInstructionType::InitializeMint => "InitializeMint",
+            ConfidentialTransferInstructionType::UpdateMint => "UpdateMint",
+            ConfidentialTransferInstructionType::ConfigureAccount => "ConfigureAccount",
+            ConfidentialTransferInstructionType::ApproveAccount => "ApproveAccount",
+            ConfidentialTransferInstructionType::EmptyAccount => "EmptyAccount",
+            ConfidentialTransferInstructionType::Deposit => "Deposit",
+            ConfidentialTransferInstructionType::Withdraw => "Withdraw",
+            ConfidentialTransferInstructionType::Transfer => "Transfer",
+            ConfidentialTransferInstructionType::ApplyPendingBalance => "ApplyPendingBalance",
+            ConfidentialTransferInstructionType::EnableConfidentialCredits => "EnableConfidentialCredits",
+            ConfidentialTransferInstructionType::DisableConfidentialCredits => "DisableConfidentialCredits",
+            ConfidentialTransferInstructionType::EnableNonConfidentialCredits => "EnableNonConfidentialCredits",
+            ConfidentialTransferInstructionType::DisableNonConfidentialCredits => "DisableNonConfidentialCredits",
+            ConfidentialTransferInstructionType::TransferWithFee => "TransferWithFee",
+            ConfidentialTransferInstructionType::ConfigureAccountWithRegistry => "ConfigureAccountWithRegistry",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "InitializeMint" => Some(Self::InitializeMint),
+            "UpdateMint" => Some(Self::UpdateMint),
+            "ConfigureAccount" => Some(Self::ConfigureAccount),
+            "ApproveAccount" => Some(Self::ApproveAccount),
+            "EmptyAccount" => Some(Self::EmptyAccount),
+            "Deposit" => Some(Self::Deposit),
+            "Withdraw" => Some(Self::Withdraw),
+            "Transfer" => Some(Self::Transfer),
+            "ApplyPendingBalance" => Some(Self::ApplyPendingBalance),
+            "EnableConfidentialCredits" => Some(Self::EnableConfidentialCredits),
+            "DisableConfidentialCredits" => Some(Self::DisableConfidentialCredits),
+            "EnableNonConfidentialCredits" => Some(Self::EnableNonConfidentialCredits),
+            "DisableNonConfidentialCredits" => Some(Self::DisableNonConfidentialCredits),
+            "TransferWithFee" => Some(Self::TransferWithFee),
+            "ConfigureAccountWithRegistry" => Some(Self::ConfigureAccountWithRegistry),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
+#[repr(i32)]
+pub enum ConfidentialTransferFeeInstructionType {
+    InitializeConfidentialTransferFeeConfig = 0,
+    WithdrawWithheldTokensFromMint = 1,
+    WithdrawWithheldTokensFromAccounts = 2,
+    HarvestWithheldTokensToMint = 3,
+    EnableHarvestToMint = 4,
+    DisableHarvestToMint = 5,
+}
+impl ConfidentialTransferFeeInstructionType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ConfidentialTransferFeeInstructionType::InitializeConfidentialTransferFeeConfig => "InitializeConfidentialTransferFeeConfig",
+            ConfidentialTransferFeeInstructionType::WithdrawWithheldTokensFromMint => "WithdrawWithheldTokensFromMint",
+            ConfidentialTransferFeeInstructionType::WithdrawWithheldTokensFromAccounts => "WithdrawWithheldTokensFromAccounts",
+            ConfidentialTransferFeeInstructionType::HarvestWithheldTokensToMint => "HarvestWithheldTokensToMint",
+            ConfidentialTransferFeeInstructionType::EnableHarvestToMint => "EnableHarvestToMint",
+            ConfidentialTransferFeeInstructionType::DisableHarvestToMint => "DisableHarvestToMint",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "InitializeConfidentialTransferFeeConfig" => Some(Self::InitializeConfidentialTransferFeeConfig),
+            "WithdrawWithheldTokensFromMint" => Some(Self::WithdrawWithheldTokensFromMint),
+            "WithdrawWithheldTokensFromAccounts" => Some(Self::WithdrawWithheldTokensFromAccounts),
+            "HarvestWithheldTokensToMint" => Some(Self::HarvestWithheldTokensToMint),
+            "EnableHarvestToMint" => Some(Self::EnableHarvestToMint),
+            "DisableHarvestToMint" => Some(Self::DisableHarvestToMint),
             _ => None,
         }
     }
@@ -0,0 +1,80 @@
+//! Registry of token accounts, for resolving the owner and mint of token accounts that a
+//! transaction's token balance metadata doesn't describe.
+//!
+//! `store_token_account_registry` keeps the owner, mint and state of every token account seen by
+//! `spl_token_registry_events`, keyed by address. `spl_token_events_resolved` decodes the same
+//! events as `spl_token_events`, falling back on the registry for token accounts missing from the
+//! metadata. Their balances stay unknown.
+//!
+//! The registry is fed by its own decode of the block, which skips instructions that fail to
+//! parse. Fed by `spl_token_events`, a block with an instruction on an unknown token account
+//! would fail under `error_policy=strict` before its accounts could be recorded.
+//!
+//! The registry is read as of the end of the block being decoded, so a token account changed
+//! later in the same block resolves to its state after the change.
+
+use anyhow::Error;
+use substreams::store::{StoreGetProto, StoreNew, StoreSet, StoreSetProto};
+use substreams_solana::pb::sf::solana::r#type::v1::Block;
+
+use solana_substreams_common::params::{ErrorPolicy, Params};
+
+use crate::pb::spl_token::{AccountState, AuthorityType, SplTokenBlockEvents, TokenAccountRecord};
+use crate::pb::spl_token::spl_token_event::Event;
+use crate::get_ordered_events;
+
+/// Token accounts by address, as kept by `store_token_account_registry`.
+pub type TokenAccountRegistry = StoreGetProto<TokenAccountRecord>;
+
+/// Decodes the events of the block's successful transactions that the registry is built from,
+/// skipping instructions that fail to parse.
+pub fn get_registry_events(block: &Block) -> Result<SplTokenBlockEvents, Error> {
+    let params = Params {
+        include_failed_transactions: false,
+        error_policy: ErrorPolicy::Skip,
+    };
+    crate::get_block_events(block, &params)
+}
+
+#[substreams::handlers::map]
+fn spl_token_registry_events(block: Block) -> Result<SplTokenBlockEvents, Error> {
+    get_registry_events(&block)
+}
+
+#[substreams::handlers::store]
+fn store_token_account_registry(events: SplTokenBlockEvents, store: StoreSetProto<TokenAccountRecord>) {
+    for (ordinal, event) in get_ordered_events(&events) {
+        let (account, owner, state) = match event {
+            Event::InitializeAccount(x) => (&x.account, None, AccountState::Initialized),
+            Event::SetAuthority(x) if x.authority_type == AuthorityType::AccountOwner as i32 => {
+                match &x.new_authority {
+                    Some(new_authority) => (&x.token_account, Some(new_authority), AccountState::Initialized),
+                    None => continue,
+                }
+            },
+            Event::FreezeAccount(x) => (&x.source, None, AccountState::Frozen),
+            Event::ThawAccount(x) => (&x.source, None, AccountState::Initialized),
+            Event::CloseAccount(x) => (&x.source, None, AccountState::Uninitialized),
+            _ => continue,
+        };
+        let account = match account {
+            Some(account) => account,
+            None => continue,
+        };
+        let record = TokenAccountRecord {
+            owner: owner.unwrap_or(&account.owner).clone(),
+            mint: account.mint.clone(),
+            state: state.into(),
+        };
+        store.set(ordinal, &account.address, &record);
+    }
+}
+
+#[substreams::handlers::map]
+fn spl_token_events_resolved(
+    params: String,
+    block: Block,
+    registry: TokenAccountRegistry,
+) -> Result<SplTokenBlockEvents, Error> {
+    crate::_get_block_events(&block, &Params::parse(&params)?, Some(&registry))
+}
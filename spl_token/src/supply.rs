@@ -0,0 +1,41 @@
+//! Running supply of mints, kept by `store_mint_supply` from the events of `spl_token_events`
+//! and output as changes by `mint_supply_deltas`.
+//!
+//! A mint's supply starts at zero with its `InitializeMint`, and is moved by `MintTo` and `Burn`.
+//! The supply of a mint initialized before the start block is only known relative to its supply at
+//! the start block. See the README for how to reconcile it.
+
+use anyhow::Error;
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaBigInt, Deltas, StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::pb::spl_token::{MintSupplyDelta, MintSupplyDeltas, SplTokenBlockEvents};
+use crate::pb::spl_token::spl_token_event::Event;
+use crate::get_ordered_events;
+
+#[substreams::handlers::store]
+fn store_mint_supply(events: SplTokenBlockEvents, store: StoreAddBigInt) {
+    for (ordinal, event) in get_ordered_events(&events) {
+        let (mint, delta) = match event {
+            Event::InitializeMint(initialize_mint) => (&initialize_mint.mint, BigInt::zero()),
+            Event::MintTo(mint_to) => (&mint_to.mint, BigInt::from(mint_to.amount)),
+            Event::Burn(burn) => (&burn.mint, -BigInt::from(burn.amount)),
+            _ => continue,
+        };
+        store.add(ordinal, mint, delta);
+    }
+}
+
+#[substreams::handlers::map]
+fn mint_supply_deltas(clock: Clock, deltas: Deltas<DeltaBigInt>) -> Result<MintSupplyDeltas, Error> {
+    Ok(MintSupplyDeltas {
+        slot: clock.number,
+        block_time: clock.timestamp.map(|x| x.seconds),
+        deltas: deltas.deltas.into_iter().map(|x| MintSupplyDelta {
+            mint: x.key,
+            old_supply: x.old_value.to_string(),
+            new_supply: x.new_value.to_string(),
+        }).collect(),
+    })
+}
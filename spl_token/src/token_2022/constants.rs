@@ -0,0 +1,5 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana::b58;
+
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey(b58!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PQnBqCXEpPxuEb"));
+pub const NATIVE_MINT_2022: Pubkey = Pubkey(b58!("9pan9bMn5HatX4EJdBwg9VgCa7Uz5HL8N1m5D3NdXejP"));
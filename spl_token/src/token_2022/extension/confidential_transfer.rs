@@ -0,0 +1,59 @@
+use crate::token_2022::instruction::{unpack_u64, unpack_u8};
+
+/// Instructions of the ConfidentialTransfer extension. Only the plaintext amounts of `Deposit`
+/// and `Withdraw` are decoded; ciphertexts and proof offsets are left in the instruction data.
+pub enum ConfidentialTransferInstruction {
+    InitializeMint,
+    UpdateMint,
+    ConfigureAccount,
+    ApproveAccount,
+    EmptyAccount,
+    Deposit {
+        amount: u64,
+        decimals: u8,
+    },
+    Withdraw {
+        amount: u64,
+        decimals: u8,
+    },
+    Transfer,
+    ApplyPendingBalance,
+    EnableConfidentialCredits,
+    DisableConfidentialCredits,
+    EnableNonConfidentialCredits,
+    DisableNonConfidentialCredits,
+    TransferWithFee,
+    ConfigureAccountWithRegistry,
+}
+
+impl ConfidentialTransferInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => Self::InitializeMint,
+            1 => Self::UpdateMint,
+            2 => Self::ConfigureAccount,
+            3 => Self::ApproveAccount,
+            4 => Self::EmptyAccount,
+            5 => {
+                let (amount, rest) = unpack_u64(rest)?;
+                let (decimals, _rest) = unpack_u8(rest)?;
+                Self::Deposit { amount, decimals }
+            }
+            6 => {
+                let (amount, rest) = unpack_u64(rest)?;
+                let (decimals, _rest) = unpack_u8(rest)?;
+                Self::Withdraw { amount, decimals }
+            }
+            7 => Self::Transfer,
+            8 => Self::ApplyPendingBalance,
+            9 => Self::EnableConfidentialCredits,
+            10 => Self::DisableConfidentialCredits,
+            11 => Self::EnableNonConfidentialCredits,
+            12 => Self::DisableNonConfidentialCredits,
+            13 => Self::TransferWithFee,
+            14 => Self::ConfigureAccountWithRegistry,
+            _ => return Err("Invalid ConfidentialTransfer instruction"),
+        })
+    }
+}
@@ -0,0 +1,33 @@
+use crate::token_2022::instruction::unpack_u8;
+
+/// Instructions of the ConfidentialTransferFee extension. Only the number of source accounts of
+/// `WithdrawWithheldTokensFromAccounts` is decoded; ciphertexts and proof offsets are left in
+/// the instruction data.
+pub enum ConfidentialTransferFeeInstruction {
+    InitializeConfidentialTransferFeeConfig,
+    WithdrawWithheldTokensFromMint,
+    WithdrawWithheldTokensFromAccounts {
+        num_token_accounts: u8,
+    },
+    HarvestWithheldTokensToMint,
+    EnableHarvestToMint,
+    DisableHarvestToMint,
+}
+
+impl ConfidentialTransferFeeInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => Self::InitializeConfidentialTransferFeeConfig,
+            1 => Self::WithdrawWithheldTokensFromMint,
+            2 => {
+                let (num_token_accounts, _rest) = unpack_u8(rest)?;
+                Self::WithdrawWithheldTokensFromAccounts { num_token_accounts }
+            }
+            3 => Self::HarvestWithheldTokensToMint,
+            4 => Self::EnableHarvestToMint,
+            5 => Self::DisableHarvestToMint,
+            _ => return Err("Invalid ConfidentialTransferFee instruction"),
+        })
+    }
+}
@@ -0,0 +1,24 @@
+use crate::token_2022::instruction::unpack_u8;
+
+/// Instructions of the DefaultAccountState extension. The state is an `AccountState`:
+/// `0` uninitialized, `1` initialized or `2` frozen.
+pub enum DefaultAccountStateInstruction {
+    Initialize {
+        state: u8,
+    },
+    Update {
+        state: u8,
+    },
+}
+
+impl DefaultAccountStateInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        let (state, _rest) = unpack_u8(rest)?;
+        Ok(match tag {
+            0 => Self::Initialize { state },
+            1 => Self::Update { state },
+            _ => return Err("Invalid DefaultAccountState instruction"),
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+use crate::token_2022::instruction::{unpack_optional_nonzero_pubkey, unpack_u8};
+
+/// Instructions of the GroupPointer extension.
+pub enum GroupPointerInstruction {
+    Initialize {
+        authority: Option<Pubkey>,
+        group_address: Option<Pubkey>,
+    },
+    Update {
+        group_address: Option<Pubkey>,
+    },
+}
+
+impl GroupPointerInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => {
+                let (authority, rest) = unpack_optional_nonzero_pubkey(rest)?;
+                let (group_address, _rest) = unpack_optional_nonzero_pubkey(rest)?;
+                Self::Initialize { authority, group_address }
+            }
+            1 => {
+                let (group_address, _rest) = unpack_optional_nonzero_pubkey(rest)?;
+                Self::Update { group_address }
+            }
+            _ => return Err("Invalid GroupPointer instruction"),
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+use crate::token_2022::instruction::{unpack_i16, unpack_optional_nonzero_pubkey, unpack_u8};
+
+/// Instructions of the InterestBearingMint extension. Rates are in basis points.
+pub enum InterestBearingMintInstruction {
+    Initialize {
+        rate_authority: Option<Pubkey>,
+        rate: i16,
+    },
+    UpdateRate {
+        rate: i16,
+    },
+}
+
+impl InterestBearingMintInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => {
+                let (rate_authority, rest) = unpack_optional_nonzero_pubkey(rest)?;
+                let (rate, _rest) = unpack_i16(rest)?;
+                Self::Initialize { rate_authority, rate }
+            }
+            1 => {
+                let (rate, _rest) = unpack_i16(rest)?;
+                Self::UpdateRate { rate }
+            }
+            _ => return Err("Invalid InterestBearingMint instruction"),
+        })
+    }
+}
@@ -0,0 +1,18 @@
+use crate::token_2022::instruction::unpack_u8;
+
+/// Instructions of the MemoTransfer extension.
+pub enum MemoTransferInstruction {
+    Enable,
+    Disable,
+}
+
+impl MemoTransferInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, _rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => Self::Enable,
+            1 => Self::Disable,
+            _ => return Err("Invalid MemoTransfer instruction"),
+        })
+    }
+}
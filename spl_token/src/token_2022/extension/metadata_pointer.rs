@@ -0,0 +1,32 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+use crate::token_2022::instruction::{unpack_optional_nonzero_pubkey, unpack_u8};
+
+/// Instructions of the MetadataPointer extension.
+pub enum MetadataPointerInstruction {
+    Initialize {
+        authority: Option<Pubkey>,
+        metadata_address: Option<Pubkey>,
+    },
+    Update {
+        metadata_address: Option<Pubkey>,
+    },
+}
+
+impl MetadataPointerInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => {
+                let (authority, rest) = unpack_optional_nonzero_pubkey(rest)?;
+                let (metadata_address, _rest) = unpack_optional_nonzero_pubkey(rest)?;
+                Self::Initialize { authority, metadata_address }
+            }
+            1 => {
+                let (metadata_address, _rest) = unpack_optional_nonzero_pubkey(rest)?;
+                Self::Update { metadata_address }
+            }
+            _ => return Err("Invalid MetadataPointer instruction"),
+        })
+    }
+}
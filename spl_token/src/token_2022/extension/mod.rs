@@ -0,0 +1,52 @@
+pub mod transfer_fee;
+pub mod confidential_transfer;
+pub mod default_account_state;
+pub mod memo_transfer;
+pub mod interest_bearing_mint;
+pub mod cpi_guard;
+pub mod transfer_hook;
+pub mod confidential_transfer_fee;
+pub mod metadata_pointer;
+pub mod group_pointer;
+pub mod scaled_ui_amount;
+pub mod pausable;
+
+/// Names of the Token-2022 `ExtensionType` variants, in discriminant order.
+pub const EXTENSION_TYPES: &[&str] = &[
+    "Uninitialized",
+    "TransferFeeConfig",
+    "TransferFeeAmount",
+    "MintCloseAuthority",
+    "ConfidentialTransferMint",
+    "ConfidentialTransferAccount",
+    "DefaultAccountState",
+    "ImmutableOwner",
+    "MemoTransfer",
+    "NonTransferable",
+    "InterestBearingConfig",
+    "CpiGuard",
+    "PermanentDelegate",
+    "NonTransferableAccount",
+    "TransferHook",
+    "TransferHookAccount",
+    "ConfidentialTransferFeeConfig",
+    "ConfidentialTransferFeeAmount",
+    "MetadataPointer",
+    "TokenMetadata",
+    "GroupPointer",
+    "TokenGroup",
+    "GroupMemberPointer",
+    "TokenGroupMember",
+    "ConfidentialMintBurn",
+    "ScaledUiAmount",
+    "Pausable",
+    "PausableAccount",
+];
+
+/// Names an `ExtensionType`, or returns `Unknown(<discriminant>)` for types added after this list.
+pub fn extension_type_name(extension_type: u16) -> String {
+    match EXTENSION_TYPES.get(extension_type as usize) {
+        Some(name) => name.to_string(),
+        None => format!("Unknown({})", extension_type),
+    }
+}
@@ -0,0 +1,27 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+use crate::token_2022::instruction::{unpack_pubkey, unpack_u8};
+
+/// Instructions of the Pausable extension.
+pub enum PausableInstruction {
+    Initialize {
+        authority: Pubkey,
+    },
+    Pause,
+    Resume,
+}
+
+impl PausableInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => {
+                let (authority, _rest) = unpack_pubkey(rest)?;
+                Self::Initialize { authority }
+            }
+            1 => Self::Pause,
+            2 => Self::Resume,
+            _ => return Err("Invalid Pausable instruction"),
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+use crate::token_2022::instruction::{unpack_f64, unpack_i64, unpack_optional_nonzero_pubkey, unpack_u8};
+
+/// Instructions of the ScaledUiAmount extension.
+pub enum ScaledUiAmountInstruction {
+    Initialize {
+        authority: Option<Pubkey>,
+        multiplier: f64,
+    },
+    UpdateMultiplier {
+        multiplier: f64,
+        effective_timestamp: i64,
+    },
+}
+
+impl ScaledUiAmountInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => {
+                let (authority, rest) = unpack_optional_nonzero_pubkey(rest)?;
+                let (multiplier, _rest) = unpack_f64(rest)?;
+                Self::Initialize { authority, multiplier }
+            }
+            1 => {
+                let (multiplier, rest) = unpack_f64(rest)?;
+                let (effective_timestamp, _rest) = unpack_i64(rest)?;
+                Self::UpdateMultiplier { multiplier, effective_timestamp }
+            }
+            _ => return Err("Invalid ScaledUiAmount instruction"),
+        })
+    }
+}
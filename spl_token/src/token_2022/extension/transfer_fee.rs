@@ -0,0 +1,65 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+use crate::token_2022::instruction::{unpack_pubkey_option, unpack_u16, unpack_u64, unpack_u8};
+
+/// Instructions of the TransferFee extension.
+pub enum TransferFeeInstruction {
+    InitializeTransferFeeConfig {
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+    TransferCheckedWithFee {
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+    },
+    WithdrawWithheldTokensFromMint,
+    WithdrawWithheldTokensFromAccounts {
+        num_token_accounts: u8,
+    },
+    HarvestWithheldTokensToMint,
+    SetTransferFee {
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    },
+}
+
+impl TransferFeeInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => {
+                let (transfer_fee_config_authority, rest) = unpack_pubkey_option(rest)?;
+                let (withdraw_withheld_authority, rest) = unpack_pubkey_option(rest)?;
+                let (transfer_fee_basis_points, rest) = unpack_u16(rest)?;
+                let (maximum_fee, _rest) = unpack_u64(rest)?;
+                Self::InitializeTransferFeeConfig {
+                    transfer_fee_config_authority,
+                    withdraw_withheld_authority,
+                    transfer_fee_basis_points,
+                    maximum_fee,
+                }
+            }
+            1 => {
+                let (amount, rest) = unpack_u64(rest)?;
+                let (decimals, rest) = unpack_u8(rest)?;
+                let (fee, _rest) = unpack_u64(rest)?;
+                Self::TransferCheckedWithFee { amount, decimals, fee }
+            }
+            2 => Self::WithdrawWithheldTokensFromMint,
+            3 => {
+                let (num_token_accounts, _rest) = unpack_u8(rest)?;
+                Self::WithdrawWithheldTokensFromAccounts { num_token_accounts }
+            }
+            4 => Self::HarvestWithheldTokensToMint,
+            5 => {
+                let (transfer_fee_basis_points, rest) = unpack_u16(rest)?;
+                let (maximum_fee, _rest) = unpack_u64(rest)?;
+                Self::SetTransferFee { transfer_fee_basis_points, maximum_fee }
+            }
+            _ => return Err("Invalid TransferFee instruction"),
+        })
+    }
+}
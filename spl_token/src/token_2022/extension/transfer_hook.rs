@@ -0,0 +1,32 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+use crate::token_2022::instruction::{unpack_optional_nonzero_pubkey, unpack_u8};
+
+/// Instructions of the TransferHook extension.
+pub enum TransferHookInstruction {
+    Initialize {
+        authority: Option<Pubkey>,
+        program_id: Option<Pubkey>,
+    },
+    Update {
+        program_id: Option<Pubkey>,
+    },
+}
+
+impl TransferHookInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, &'static str> {
+        let (tag, rest) = unpack_u8(input)?;
+        Ok(match tag {
+            0 => {
+                let (authority, rest) = unpack_optional_nonzero_pubkey(rest)?;
+                let (program_id, _rest) = unpack_optional_nonzero_pubkey(rest)?;
+                Self::Initialize { authority, program_id }
+            }
+            1 => {
+                let (program_id, _rest) = unpack_optional_nonzero_pubkey(rest)?;
+                Self::Update { program_id }
+            }
+            _ => return Err("Invalid TransferHook instruction"),
+        })
+    }
+}
@@ -0,0 +1,200 @@
+use substreams_solana_utils::pubkey::Pubkey;
+use substreams_solana_utils::spl_token::TokenInstruction;
+
+use super::extension::transfer_fee::TransferFeeInstruction;
+use super::extension::confidential_transfer::ConfidentialTransferInstruction;
+use super::extension::default_account_state::DefaultAccountStateInstruction;
+use super::extension::memo_transfer::MemoTransferInstruction;
+use super::extension::interest_bearing_mint::InterestBearingMintInstruction;
+use super::extension::cpi_guard::CpiGuardInstruction;
+use super::extension::transfer_hook::TransferHookInstruction;
+use super::extension::confidential_transfer_fee::ConfidentialTransferFeeInstruction;
+use super::extension::metadata_pointer::MetadataPointerInstruction;
+use super::extension::group_pointer::GroupPointerInstruction;
+use super::extension::scaled_ui_amount::ScaledUiAmountInstruction;
+use super::extension::pausable::PausableInstruction;
+use super::interface::token_metadata::TokenMetadataInstruction;
+use super::interface::token_group::TokenGroupInstruction;
+
+/// Last instruction tag shared with the Token program. Token-2022 encodes these instructions
+/// the same way, and adds its extension instructions after them.
+const LAST_TOKEN_INSTRUCTION_TAG: u8 = 24;
+
+const SET_AUTHORITY_TAG: u8 = 6;
+const INITIALIZE_MINT_CLOSE_AUTHORITY_TAG: u8 = 25;
+const TRANSFER_FEE_EXTENSION_TAG: u8 = 26;
+const CONFIDENTIAL_TRANSFER_EXTENSION_TAG: u8 = 27;
+const DEFAULT_ACCOUNT_STATE_EXTENSION_TAG: u8 = 28;
+const REALLOCATE_TAG: u8 = 29;
+const MEMO_TRANSFER_EXTENSION_TAG: u8 = 30;
+const INITIALIZE_NON_TRANSFERABLE_MINT_TAG: u8 = 32;
+const INTEREST_BEARING_MINT_EXTENSION_TAG: u8 = 33;
+const CPI_GUARD_EXTENSION_TAG: u8 = 34;
+const INITIALIZE_PERMANENT_DELEGATE_TAG: u8 = 35;
+const TRANSFER_HOOK_EXTENSION_TAG: u8 = 36;
+const CONFIDENTIAL_TRANSFER_FEE_EXTENSION_TAG: u8 = 37;
+const METADATA_POINTER_EXTENSION_TAG: u8 = 39;
+const GROUP_POINTER_EXTENSION_TAG: u8 = 40;
+const SCALED_UI_AMOUNT_EXTENSION_TAG: u8 = 43;
+const PAUSABLE_EXTENSION_TAG: u8 = 44;
+
+pub enum Token2022Instruction {
+    /// An instruction of the Token program's instruction set.
+    Token(TokenInstruction),
+    /// `SetAuthority`, whose authority type can be one of the Token-2022 authority types, as
+    /// its `AuthorityType` discriminant.
+    SetAuthority {
+        authority_type: u8,
+        new_authority: Option<Pubkey>,
+    },
+    InitializeMintCloseAuthority {
+        close_authority: Option<Pubkey>,
+    },
+    TransferFee(TransferFeeInstruction),
+    ConfidentialTransfer(ConfidentialTransferInstruction),
+    DefaultAccountState(DefaultAccountStateInstruction),
+    /// Extension types, as `ExtensionType` discriminants, to make room for in a token account.
+    Reallocate {
+        extension_types: Vec<u16>,
+    },
+    MemoTransfer(MemoTransferInstruction),
+    InitializeNonTransferableMint,
+    InterestBearingMint(InterestBearingMintInstruction),
+    CpiGuard(CpiGuardInstruction),
+    InitializePermanentDelegate {
+        delegate: Pubkey,
+    },
+    TransferHook(TransferHookInstruction),
+    ConfidentialTransferFee(ConfidentialTransferFeeInstruction),
+    MetadataPointer(MetadataPointerInstruction),
+    GroupPointer(GroupPointerInstruction),
+    ScaledUiAmount(ScaledUiAmountInstruction),
+    Pausable(PausableInstruction),
+    /// An instruction of the token-metadata interface.
+    TokenMetadata(TokenMetadataInstruction),
+    /// An instruction of the token-group interface.
+    TokenGroup(TokenGroupInstruction),
+    /// An extension instruction that isn't decoded, with its tag.
+    Extension(u8),
+}
+
+impl Token2022Instruction {
+    pub fn unpack(data: &[u8]) -> Result<Self, String> {
+        // Interface instructions start with an 8-byte discriminator instead of a tag.
+        if let Some(unpacked) = TokenMetadataInstruction::unpack(data) {
+            return Ok(Self::TokenMetadata(unpacked?));
+        }
+        if let Some(unpacked) = TokenGroupInstruction::unpack(data) {
+            return Ok(Self::TokenGroup(unpacked?));
+        }
+        let (&tag, rest) = data.split_first().ok_or("Invalid instruction data")?;
+        match tag {
+            SET_AUTHORITY_TAG => {
+                let (authority_type, rest) = unpack_u8(rest)?;
+                let (new_authority, _rest) = unpack_pubkey_option(rest)?;
+                Ok(Self::SetAuthority { authority_type, new_authority })
+            }
+            0..=LAST_TOKEN_INSTRUCTION_TAG => TokenInstruction::unpack(data).map(Self::Token).map_err(|x| x.to_string()),
+            INITIALIZE_MINT_CLOSE_AUTHORITY_TAG => {
+                let (close_authority, _rest) = unpack_pubkey_option(rest)?;
+                Ok(Self::InitializeMintCloseAuthority { close_authority })
+            }
+            TRANSFER_FEE_EXTENSION_TAG => Ok(Self::TransferFee(TransferFeeInstruction::unpack(rest)?)),
+            CONFIDENTIAL_TRANSFER_EXTENSION_TAG => Ok(Self::ConfidentialTransfer(ConfidentialTransferInstruction::unpack(rest)?)),
+            DEFAULT_ACCOUNT_STATE_EXTENSION_TAG => Ok(Self::DefaultAccountState(DefaultAccountStateInstruction::unpack(rest)?)),
+            REALLOCATE_TAG => {
+                if rest.len() % 2 != 0 {
+                    return Err("Invalid instruction data".to_string());
+                }
+                let extension_types = rest.chunks_exact(2).map(|x| u16::from_le_bytes([x[0], x[1]])).collect();
+                Ok(Self::Reallocate { extension_types })
+            }
+            MEMO_TRANSFER_EXTENSION_TAG => Ok(Self::MemoTransfer(MemoTransferInstruction::unpack(rest)?)),
+            INITIALIZE_NON_TRANSFERABLE_MINT_TAG => Ok(Self::InitializeNonTransferableMint),
+            INTEREST_BEARING_MINT_EXTENSION_TAG => Ok(Self::InterestBearingMint(InterestBearingMintInstruction::unpack(rest)?)),
+            CPI_GUARD_EXTENSION_TAG => Ok(Self::CpiGuard(CpiGuardInstruction::unpack(rest)?)),
+            INITIALIZE_PERMANENT_DELEGATE_TAG => {
+                let (delegate, _rest) = unpack_pubkey(rest)?;
+                Ok(Self::InitializePermanentDelegate { delegate })
+            }
+            TRANSFER_HOOK_EXTENSION_TAG => Ok(Self::TransferHook(TransferHookInstruction::unpack(rest)?)),
+            CONFIDENTIAL_TRANSFER_FEE_EXTENSION_TAG => Ok(Self::ConfidentialTransferFee(ConfidentialTransferFeeInstruction::unpack(rest)?)),
+            METADATA_POINTER_EXTENSION_TAG => Ok(Self::MetadataPointer(MetadataPointerInstruction::unpack(rest)?)),
+            GROUP_POINTER_EXTENSION_TAG => Ok(Self::GroupPointer(GroupPointerInstruction::unpack(rest)?)),
+            SCALED_UI_AMOUNT_EXTENSION_TAG => Ok(Self::ScaledUiAmount(ScaledUiAmountInstruction::unpack(rest)?)),
+            PAUSABLE_EXTENSION_TAG => Ok(Self::Pausable(PausableInstruction::unpack(rest)?)),
+            _ => Ok(Self::Extension(tag)),
+        }
+    }
+}
+
+pub(crate) fn unpack_u8(input: &[u8]) -> Result<(u8, &[u8]), &'static str> {
+    let (&value, rest) = input.split_first().ok_or("Invalid instruction data")?;
+    Ok((value, rest))
+}
+
+pub(crate) fn unpack_bool(input: &[u8]) -> Result<(bool, &[u8]), &'static str> {
+    match unpack_u8(input)? {
+        (0, rest) => Ok((false, rest)),
+        (1, rest) => Ok((true, rest)),
+        _ => Err("Invalid instruction data"),
+    }
+}
+
+pub(crate) fn unpack_u16(input: &[u8]) -> Result<(u16, &[u8]), &'static str> {
+    let bytes = input.get(..2).ok_or("Invalid instruction data")?;
+    Ok((u16::from_le_bytes([bytes[0], bytes[1]]), &input[2..]))
+}
+
+pub(crate) fn unpack_i16(input: &[u8]) -> Result<(i16, &[u8]), &'static str> {
+    let (value, rest) = unpack_u16(input)?;
+    Ok((value as i16, rest))
+}
+
+pub(crate) fn unpack_u32(input: &[u8]) -> Result<(u32, &[u8]), &'static str> {
+    let bytes = input.get(..4).and_then(|x| x.try_into().ok()).ok_or("Invalid instruction data")?;
+    Ok((u32::from_le_bytes(bytes), &input[4..]))
+}
+
+pub(crate) fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), &'static str> {
+    let bytes = input.get(..8).and_then(|x| x.try_into().ok()).ok_or("Invalid instruction data")?;
+    Ok((u64::from_le_bytes(bytes), &input[8..]))
+}
+
+pub(crate) fn unpack_i64(input: &[u8]) -> Result<(i64, &[u8]), &'static str> {
+    let (value, rest) = unpack_u64(input)?;
+    Ok((value as i64, rest))
+}
+
+pub(crate) fn unpack_f64(input: &[u8]) -> Result<(f64, &[u8]), &'static str> {
+    let (value, rest) = unpack_u64(input)?;
+    Ok((f64::from_bits(value), rest))
+}
+
+pub(crate) fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), &'static str> {
+    let bytes = input.get(..32).and_then(|x| x.try_into().ok()).ok_or("Invalid instruction data")?;
+    Ok((Pubkey(bytes), &input[32..]))
+}
+
+/// Unpacks a `COption<Pubkey>`, encoded as a `0` or `1` tag followed by the key when set.
+pub(crate) fn unpack_pubkey_option(input: &[u8]) -> Result<(Option<Pubkey>, &[u8]), &'static str> {
+    match unpack_u8(input)? {
+        (0, rest) => Ok((None, rest)),
+        (1, rest) => unpack_pubkey(rest).map(|(pubkey, rest)| (Some(pubkey), rest)),
+        _ => Err("Invalid instruction data"),
+    }
+}
+
+/// Unpacks an `OptionalNonZeroPubkey`, where the all-zero key stands for `None`.
+pub(crate) fn unpack_optional_nonzero_pubkey(input: &[u8]) -> Result<(Option<Pubkey>, &[u8]), &'static str> {
+    let (pubkey, rest) = unpack_pubkey(input)?;
+    Ok(((pubkey.0 != [0; 32]).then_some(pubkey), rest))
+}
+
+/// Unpacks a borsh `String`, encoded as its `u32` length followed by its UTF-8 bytes.
+pub(crate) fn unpack_string(input: &[u8]) -> Result<(String, &[u8]), &'static str> {
+    let (len, rest) = unpack_u32(input)?;
+    let bytes = rest.get(..len as usize).ok_or("Invalid instruction data")?;
+    let value = String::from_utf8(bytes.to_vec()).map_err(|_| "Invalid instruction data")?;
+    Ok((value, &rest[len as usize..]))
+}
@@ -0,0 +1,2 @@
+pub mod token_metadata;
+pub mod token_group;
@@ -0,0 +1,47 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+use crate::token_2022::instruction::{unpack_optional_nonzero_pubkey, unpack_u64};
+
+pub const INITIALIZE_GROUP_DISCRIMINATOR: [u8; 8] = [121, 113, 108, 39, 54, 51, 0, 4];
+pub const UPDATE_GROUP_MAX_SIZE_DISCRIMINATOR: [u8; 8] = [108, 37, 171, 143, 248, 30, 18, 110];
+pub const UPDATE_GROUP_AUTHORITY_DISCRIMINATOR: [u8; 8] = [161, 105, 88, 1, 237, 221, 216, 203];
+pub const INITIALIZE_MEMBER_DISCRIMINATOR: [u8; 8] = [152, 32, 222, 176, 223, 237, 116, 134];
+
+/// Instructions of the spl-token-group-interface, which Token-2022 implements for groups and
+/// members stored in the mint. Identified by an 8-byte discriminator rather than a tag.
+pub enum TokenGroupInstruction {
+    InitializeGroup {
+        update_authority: Option<Pubkey>,
+        max_size: u64,
+    },
+    UpdateGroupMaxSize {
+        max_size: u64,
+    },
+    UpdateGroupAuthority {
+        new_authority: Option<Pubkey>,
+    },
+    InitializeMember,
+}
+
+impl TokenGroupInstruction {
+    /// Unpacks the instruction, or returns `None` if the data doesn't start with one of the
+    /// interface's discriminators.
+    pub fn unpack(input: &[u8]) -> Option<Result<Self, &'static str>> {
+        let discriminator: [u8; 8] = input.get(..8)?.try_into().ok()?;
+        let rest = &input[8..];
+        Some(match discriminator {
+            INITIALIZE_GROUP_DISCRIMINATOR => unpack_optional_nonzero_pubkey(rest).and_then(|(update_authority, rest)| {
+                let (max_size, _rest) = unpack_u64(rest)?;
+                Ok(Self::InitializeGroup { update_authority, max_size })
+            }),
+            UPDATE_GROUP_MAX_SIZE_DISCRIMINATOR => {
+                unpack_u64(rest).map(|(max_size, _rest)| Self::UpdateGroupMaxSize { max_size })
+            }
+            UPDATE_GROUP_AUTHORITY_DISCRIMINATOR => {
+                unpack_optional_nonzero_pubkey(rest).map(|(new_authority, _rest)| Self::UpdateGroupAuthority { new_authority })
+            }
+            INITIALIZE_MEMBER_DISCRIMINATOR => Ok(Self::InitializeMember),
+            _ => return None,
+        })
+    }
+}
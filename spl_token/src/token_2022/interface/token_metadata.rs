@@ -0,0 +1,104 @@
+use substreams_solana_utils::pubkey::Pubkey;
+
+use crate::token_2022::instruction::{unpack_bool, unpack_optional_nonzero_pubkey, unpack_string, unpack_u64, unpack_u8};
+
+pub const INITIALIZE_DISCRIMINATOR: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+pub const UPDATE_FIELD_DISCRIMINATOR: [u8; 8] = [221, 233, 49, 45, 181, 202, 220, 200];
+pub const REMOVE_KEY_DISCRIMINATOR: [u8; 8] = [234, 18, 32, 56, 89, 141, 37, 181];
+pub const UPDATE_AUTHORITY_DISCRIMINATOR: [u8; 8] = [215, 228, 166, 228, 84, 100, 86, 123];
+pub const EMIT_DISCRIMINATOR: [u8; 8] = [250, 166, 180, 250, 13, 12, 184, 70];
+
+/// A field of the token metadata.
+pub enum Field {
+    Name,
+    Symbol,
+    Uri,
+    /// A custom field, in the additional metadata.
+    Key(String),
+}
+
+/// Instructions of the spl-token-metadata-interface, which Token-2022 implements for metadata
+/// stored in the mint. Identified by an 8-byte discriminator rather than a tag.
+pub enum TokenMetadataInstruction {
+    Initialize {
+        name: String,
+        symbol: String,
+        uri: String,
+    },
+    UpdateField {
+        field: Field,
+        value: String,
+    },
+    RemoveKey {
+        idempotent: bool,
+        key: String,
+    },
+    UpdateAuthority {
+        new_authority: Option<Pubkey>,
+    },
+    Emit {
+        start: Option<u64>,
+        end: Option<u64>,
+    },
+}
+
+impl TokenMetadataInstruction {
+    /// Unpacks the instruction, or returns `None` if the data doesn't start with one of the
+    /// interface's discriminators.
+    pub fn unpack(input: &[u8]) -> Option<Result<Self, &'static str>> {
+        let discriminator: [u8; 8] = input.get(..8)?.try_into().ok()?;
+        let rest = &input[8..];
+        Some(match discriminator {
+            INITIALIZE_DISCRIMINATOR => Self::unpack_initialize(rest),
+            UPDATE_FIELD_DISCRIMINATOR => Self::unpack_update_field(rest),
+            REMOVE_KEY_DISCRIMINATOR => Self::unpack_remove_key(rest),
+            UPDATE_AUTHORITY_DISCRIMINATOR => {
+                unpack_optional_nonzero_pubkey(rest).map(|(new_authority, _rest)| Self::UpdateAuthority { new_authority })
+            }
+            EMIT_DISCRIMINATOR => Self::unpack_emit(rest),
+            _ => return None,
+        })
+    }
+
+    fn unpack_initialize(input: &[u8]) -> Result<Self, &'static str> {
+        let (name, rest) = unpack_string(input)?;
+        let (symbol, rest) = unpack_string(rest)?;
+        let (uri, _rest) = unpack_string(rest)?;
+        Ok(Self::Initialize { name, symbol, uri })
+    }
+
+    fn unpack_update_field(input: &[u8]) -> Result<Self, &'static str> {
+        let (field, rest) = match unpack_u8(input)? {
+            (0, rest) => (Field::Name, rest),
+            (1, rest) => (Field::Symbol, rest),
+            (2, rest) => (Field::Uri, rest),
+            (3, rest) => {
+                let (key, rest) = unpack_string(rest)?;
+                (Field::Key(key), rest)
+            }
+            _ => return Err("Invalid token metadata field"),
+        };
+        let (value, _rest) = unpack_string(rest)?;
+        Ok(Self::UpdateField { field, value })
+    }
+
+    fn unpack_remove_key(input: &[u8]) -> Result<Self, &'static str> {
+        let (idempotent, rest) = unpack_bool(input)?;
+        let (key, _rest) = unpack_string(rest)?;
+        Ok(Self::RemoveKey { idempotent, key })
+    }
+
+    fn unpack_emit(input: &[u8]) -> Result<Self, &'static str> {
+        let (start, rest) = unpack_u64_option(input)?;
+        let (end, _rest) = unpack_u64_option(rest)?;
+        Ok(Self::Emit { start, end })
+    }
+}
+
+/// Unpacks a borsh `Option<u64>`.
+fn unpack_u64_option(input: &[u8]) -> Result<(Option<u64>, &[u8]), &'static str> {
+    match unpack_bool(input)? {
+        (false, rest) => Ok((None, rest)),
+        (true, rest) => unpack_u64(rest).map(|(value, rest)| (Some(value), rest)),
+    }
+}
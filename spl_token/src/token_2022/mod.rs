@@ -0,0 +1,5 @@
+pub mod constants;
+pub use constants::{TOKEN_2022_PROGRAM_ID, NATIVE_MINT_2022};
+pub mod instruction;
+pub mod extension;
+pub mod interface;
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use substreams::scalar::BigInt;
+
+use solana_substreams_common::pb::solana_common::TokenAccount;
+
+use spl_token_substream::{get_holder_count_change, get_owner_balance_changes, get_token_account_states};
+use spl_token_substream::pb::spl_token::{AuthorityType, CloseAccountEvent, MintToEvent, SetAuthorityEvent};
+use spl_token_substream::pb::spl_token::spl_token_event::Event;
+
+const MINT: &str = "mint";
+
+/// The three balance stores, kept in memory.
+#[derive(Default)]
+struct Balances {
+    token_accounts: HashMap<String, TokenAccount>,
+    owner_balances: HashMap<String, BigInt>,
+    mint_holders: HashMap<String, i64>,
+}
+
+impl Balances {
+    fn apply(&mut self, event: Event) {
+        for account in get_token_account_states(&event) {
+            let old = self.token_accounts.insert(account.address.clone(), account.clone()).unwrap_or_default();
+            for (key, change) in get_owner_balance_changes(&old, &account) {
+                let old_balance = self.owner_balances.get(&key).cloned().unwrap_or_else(BigInt::zero);
+                let new_balance = old_balance.clone() + change;
+                if let Some((mint, change)) = get_holder_count_change(&key, &old_balance, &new_balance) {
+                    *self.mint_holders.entry(mint.to_string()).or_default() += change;
+                }
+                self.owner_balances.insert(key, new_balance);
+            }
+        }
+    }
+
+    fn owner_balance(&self, owner: &str) -> BigInt {
+        self.owner_balances.get(&format!("{}:{}", MINT, owner)).cloned().unwrap_or_else(BigInt::zero)
+    }
+
+    fn holder_count(&self) -> i64 {
+        self.mint_holders.get(MINT).copied().unwrap_or(0)
+    }
+}
+
+fn token_account(address: &str, owner: &str, pre_balance: u64, post_balance: u64) -> TokenAccount {
+    TokenAccount {
+        address: address.to_string(),
+        owner: owner.to_string(),
+        mint: MINT.to_string(),
+        pre_balance: Some(pre_balance),
+        post_balance: Some(post_balance),
+    }
+}
+
+fn mint_to(destination: TokenAccount) -> Event {
+    Event::MintTo(MintToEvent {
+        amount: destination.post_balance.unwrap() - destination.pre_balance.unwrap(),
+        destination: Some(destination),
+        mint: MINT.to_string(),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn set_authority_moves_the_balance_to_the_new_owner() {
+    let mut balances = Balances::default();
+    balances.apply(mint_to(token_account("account", "alice", 0, 100)));
+    balances.apply(Event::SetAuthority(SetAuthorityEvent {
+        mint: MINT.to_string(),
+        authority_type: AuthorityType::AccountOwner.into(),
+        token_account: Some(token_account("account", "alice", 100, 100)),
+        new_authority: Some("bob".to_string()),
+        ..Default::default()
+    }));
+
+    assert_eq!(balances.owner_balance("alice"), BigInt::zero());
+    assert_eq!(balances.owner_balance("bob"), BigInt::from(100u64));
+    assert_eq!(balances.holder_count(), 1);
+}
+
+#[test]
+fn close_account_drops_the_holder() {
+    let mut balances = Balances::default();
+    balances.apply(mint_to(token_account("account", "alice", 0, 100)));
+    assert_eq!(balances.holder_count(), 1);
+
+    // A wrapped SOL account can be closed with tokens left in it.
+    balances.apply(Event::CloseAccount(CloseAccountEvent {
+        source: Some(token_account("account", "alice", 100, 100)),
+        ..Default::default()
+    }));
+
+    assert_eq!(balances.owner_balance("alice"), BigInt::zero());
+    assert_eq!(balances.holder_count(), 0);
+}
+
+#[test]
+fn token_accounts_of_the_same_owner_count_as_one_holder() {
+    let mut balances = Balances::default();
+    balances.apply(mint_to(token_account("account", "alice", 0, 100)));
+    balances.apply(mint_to(token_account("other_account", "alice", 0, 50)));
+
+    assert_eq!(balances.owner_balance("alice"), BigInt::from(150u64));
+    assert_eq!(balances.holder_count(), 1);
+}
@@ -0,0 +1,205 @@
+use substreams_solana::pb::sf::solana::r#type::v1::{
+    CompiledInstruction, ConfirmedTransaction, InnerInstruction, InnerInstructions, Message, MessageHeader,
+    TokenBalance, Transaction, TransactionStatusMeta, UiTokenAmount,
+};
+
+use solana_substreams_common::failure::ParseFailures;
+use solana_substreams_common::params::ErrorPolicy;
+
+use spl_token_substream::{parse_transaction, TOKEN_PROGRAM_IDS};
+use spl_token_substream::pb::spl_token::TransferEvent;
+use spl_token_substream::pb::spl_token::spl_token_event::Event;
+
+const AMOUNT: u64 = 100;
+
+const OWNER: [u8; 32] = [1; 32];
+const HOOK_ACCOUNT: [u8; 32] = [2; 32];
+const SOURCE: [u8; 32] = [3; 32];
+const DESTINATION: [u8; 32] = [4; 32];
+const MINT: [u8; 32] = [5; 32];
+const MULTISIG: [u8; 32] = [6; 32];
+const PDA: [u8; 32] = [7; 32];
+const VALIDATION: [u8; 32] = [8; 32];
+const CALLER_PROGRAM: [u8; 32] = [9; 32];
+const HOOK_PROGRAM: [u8; 32] = [10; 32];
+
+/// An instruction as (program, accounts, data), indexing into the account keys.
+type Instruction = (u32, Vec<u8>, Vec<u8>);
+
+fn address(key: [u8; 32]) -> String {
+    bs58::encode(key).into_string()
+}
+
+/// A token account of `MINT` at `index` in the account keys, owned by `owner` and holding `amount`.
+fn token_balance(index: u32, owner: [u8; 32], amount: u64, token_program: usize) -> TokenBalance {
+    TokenBalance {
+        account_index: index,
+        mint: address(MINT),
+        ui_token_amount: Some(UiTokenAmount {
+            ui_amount: amount as f64,
+            decimals: 0,
+            amount: amount.to_string(),
+            ui_amount_string: amount.to_string(),
+        }),
+        owner: address(owner),
+        program_id: TOKEN_PROGRAM_IDS[token_program].to_string(),
+    }
+}
+
+/// A successful transaction signed by the first `signers` of `keys`, moving `AMOUNT` from `SOURCE`
+/// at index 2 to `DESTINATION` at index 3. Each instruction comes with the instructions it
+/// invokes, along with their stack height.
+fn transaction(
+    keys: &[[u8; 32]],
+    signers: u32,
+    instructions: Vec<(Instruction, Vec<(u32, Instruction)>)>,
+    source_owner: [u8; 32],
+    token_program: usize,
+) -> ConfirmedTransaction {
+    let program_id = |index: u32| address(keys[index as usize]);
+    let mut log_messages = Vec::new();
+    for ((program_id_index, _, _), inner_instructions) in &instructions {
+        log_messages.push(format!("Program {} invoke [1]", program_id(*program_id_index)));
+        let mut stack = vec![*program_id_index];
+        for (stack_height, (program_id_index, _, _)) in inner_instructions {
+            while stack.len() >= *stack_height as usize {
+                log_messages.push(format!("Program {} success", program_id(stack.pop().unwrap())));
+            }
+            log_messages.push(format!("Program {} invoke [{}]", program_id(*program_id_index), stack_height));
+            stack.push(*program_id_index);
+        }
+        while let Some(program_id_index) = stack.pop() {
+            log_messages.push(format!("Program {} success", program_id(program_id_index)));
+        }
+    }
+    let inner_instructions = instructions.iter().enumerate().map(|(index, (_, inner_instructions))| InnerInstructions {
+        index: index as u32,
+        instructions: inner_instructions.iter().map(|(stack_height, (program_id_index, accounts, data))| InnerInstruction {
+            program_id_index: *program_id_index,
+            accounts: accounts.clone(),
+            data: data.clone(),
+            stack_height: Some(*stack_height),
+        }).collect(),
+    }).filter(|x| !x.instructions.is_empty()).collect();
+    ConfirmedTransaction {
+        transaction: Some(Transaction {
+            signatures: vec![vec![7; 64]; signers as usize],
+            message: Some(Message {
+                header: Some(MessageHeader {
+                    num_required_signatures: signers,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 0,
+                }),
+                account_keys: keys.iter().map(|x| x.to_vec()).collect(),
+                recent_blockhash: vec![9; 32],
+                instructions: instructions.into_iter().map(|((program_id_index, accounts, data), _)| CompiledInstruction {
+                    program_id_index,
+                    accounts,
+                    data,
+                }).collect(),
+                ..Default::default()
+            }),
+        }),
+        meta: Some(TransactionStatusMeta {
+            fee: 5000,
+            pre_balances: vec![1; keys.len()],
+            post_balances: vec![1; keys.len()],
+            pre_token_balances: vec![
+                token_balance(2, source_owner, AMOUNT, token_program),
+                token_balance(3, OWNER, 0, token_program),
+            ],
+            post_token_balances: vec![
+                token_balance(2, source_owner, 0, token_program),
+                token_balance(3, OWNER, AMOUNT, token_program),
+            ],
+            inner_instructions,
+            log_messages,
+            ..Default::default()
+        }),
+    }
+}
+
+fn transfer() -> Vec<u8> {
+    [&[3u8][..], &AMOUNT.to_le_bytes()].concat()
+}
+
+fn transfer_checked() -> Vec<u8> {
+    [&[12u8][..], &AMOUNT.to_le_bytes(), &[0]].concat()
+}
+
+/// The transfer hook interface's `Execute`.
+fn execute() -> Vec<u8> {
+    [&[105u8, 37, 101, 197, 75, 251, 102, 26][..], &AMOUNT.to_le_bytes()].concat()
+}
+
+fn get_transfer(transaction: &ConfirmedTransaction) -> TransferEvent {
+    let events = parse_transaction(transaction, &mut ParseFailures::new(ErrorPolicy::Strict)).unwrap();
+    events.into_iter().find_map(|x| match x.event {
+        Some(Event::Transfer(transfer)) => Some(transfer),
+        _ => None,
+    }).unwrap()
+}
+
+#[test]
+fn co_signers_signing_through_a_program_are_reported() {
+    // The caller invokes the transfer, signing for the PDA co-signer, which didn't sign the
+    // transaction.
+    let keys = [OWNER, PDA, SOURCE, DESTINATION, MULTISIG, CALLER_PROGRAM, TOKEN_PROGRAM_IDS[0].0];
+    let transaction = transaction(
+        &keys,
+        1,
+        vec![((5, vec![2, 3, 4, 0, 1, 6], vec![]), vec![(2, (6, vec![2, 3, 4, 0, 1], transfer()))])],
+        MULTISIG,
+        0,
+    );
+    let transfer = get_transfer(&transaction);
+
+    assert_eq!(transfer.authority, address(MULTISIG));
+    assert_eq!(transfer.signers, vec![address(OWNER), address(PDA)]);
+    assert!(transfer.authority_is_multisig);
+}
+
+#[test]
+fn hook_accounts_that_signed_the_transaction_are_not_co_signers() {
+    let keys = [OWNER, HOOK_ACCOUNT, SOURCE, DESTINATION, MINT, VALIDATION, HOOK_PROGRAM, TOKEN_PROGRAM_IDS[1].0];
+    let transaction = transaction(
+        &keys,
+        2,
+        vec![((7, vec![2, 4, 3, 0, 1, 5, 6], transfer_checked()), vec![(2, (6, vec![2, 4, 3, 0, 5, 1], execute()))])],
+        OWNER,
+        1,
+    );
+    let transfer = get_transfer(&transaction);
+
+    assert_eq!(transfer.authority, address(OWNER));
+    assert_eq!(transfer.signers, Vec::<String>::new());
+    assert!(!transfer.authority_is_multisig);
+}
+
+#[test]
+fn co_signers_are_told_apart_from_hook_accounts() {
+    // A program transfers from a multisig it co-signs for, and Token-2022 invokes the hook.
+    let keys = [
+        OWNER, HOOK_ACCOUNT, SOURCE, DESTINATION, MINT, MULTISIG, PDA, VALIDATION, HOOK_PROGRAM, CALLER_PROGRAM,
+        TOKEN_PROGRAM_IDS[1].0,
+    ];
+    let accounts = vec![2, 4, 3, 5, 0, 6, 1, 7, 8];
+    let transaction = transaction(
+        &keys,
+        2,
+        vec![(
+            (9, [accounts.clone(), vec![10]].concat(), vec![]),
+            vec![
+                (2, (10, accounts, transfer_checked())),
+                (3, (8, vec![2, 4, 3, 5, 7, 1], execute())),
+            ],
+        )],
+        MULTISIG,
+        1,
+    );
+    let transfer = get_transfer(&transaction);
+
+    assert_eq!(transfer.authority, address(MULTISIG));
+    assert_eq!(transfer.signers, vec![address(OWNER), address(PDA)]);
+    assert!(transfer.authority_is_multisig);
+}
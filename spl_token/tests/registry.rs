@@ -0,0 +1,106 @@
+use substreams_solana::pb::sf::solana::r#type::v1::{
+    Block, CompiledInstruction, ConfirmedTransaction, Message, MessageHeader, TokenBalance, Transaction,
+    TransactionStatusMeta, UiTokenAmount,
+};
+
+use solana_substreams_common::params::Params;
+
+use spl_token_substream::{get_block_events, get_registry_events, TOKEN_PROGRAM_IDS};
+use spl_token_substream::pb::spl_token::spl_token_event::Event;
+
+const OWNER: [u8; 32] = [1; 32];
+const ACCOUNT: [u8; 32] = [2; 32];
+const MINT: [u8; 32] = [3; 32];
+/// A token account initialized in an earlier block.
+const KNOWN_ACCOUNT: [u8; 32] = [4; 32];
+
+fn address(key: [u8; 32]) -> String {
+    bs58::encode(key).into_string()
+}
+
+/// An empty token account of `OWNER` at `index` in the account keys.
+fn token_balance(index: u32) -> TokenBalance {
+    TokenBalance {
+        account_index: index,
+        mint: address(MINT),
+        ui_token_amount: Some(UiTokenAmount {
+            ui_amount: 0.0,
+            decimals: 0,
+            amount: "0".to_string(),
+            ui_amount_string: "0".to_string(),
+        }),
+        owner: address(OWNER),
+        program_id: TOKEN_PROGRAM_IDS[0].to_string(),
+    }
+}
+
+/// A successful transaction signed by `OWNER`, the first of `keys`, with a single Token
+/// instruction over `accounts`.
+fn transaction(signature: u8, keys: &[[u8; 32]], accounts: Vec<u8>, data: Vec<u8>, token_balances: Vec<TokenBalance>) -> ConfirmedTransaction {
+    let program_id = TOKEN_PROGRAM_IDS[0].to_string();
+    ConfirmedTransaction {
+        transaction: Some(Transaction {
+            signatures: vec![vec![signature; 64]],
+            message: Some(Message {
+                header: Some(MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 1,
+                }),
+                account_keys: keys.iter().chain([&TOKEN_PROGRAM_IDS[0].0]).map(|x| x.to_vec()).collect(),
+                recent_blockhash: vec![9; 32],
+                instructions: vec![CompiledInstruction {
+                    program_id_index: keys.len() as u32,
+                    accounts,
+                    data,
+                }],
+                ..Default::default()
+            }),
+        }),
+        meta: Some(TransactionStatusMeta {
+            fee: 5000,
+            pre_balances: vec![1; keys.len() + 1],
+            post_balances: vec![1; keys.len() + 1],
+            pre_token_balances: token_balances.clone(),
+            post_token_balances: token_balances,
+            log_messages: vec![
+                format!("Program {} invoke [1]", program_id),
+                format!("Program {} success", program_id),
+            ],
+            ..Default::default()
+        }),
+    }
+}
+
+/// A block that initializes `ACCOUNT`, then revokes the delegate of `KNOWN_ACCOUNT`, which the
+/// metadata of the revoking transaction doesn't describe.
+fn block() -> Block {
+    Block {
+        slot: 1,
+        transactions: vec![
+            transaction(1, &[OWNER, ACCOUNT, MINT], vec![1, 2], [&[18u8][..], &OWNER].concat(), vec![token_balance(1)]),
+            transaction(2, &[OWNER, KNOWN_ACCOUNT], vec![1, 0], vec![5], vec![]),
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn registry_records_blocks_the_strict_policy_fails_on() {
+    let block = block();
+    assert!(get_block_events(&block, &Params::default()).is_err());
+
+    let events = get_registry_events(&block).unwrap();
+    assert_eq!(events.parse_failure_count, 1);
+    let initialize_account = events.transactions.iter()
+        .flat_map(|x| &x.events)
+        .find_map(|x| match &x.event {
+            Some(Event::InitializeAccount(initialize_account)) => Some(initialize_account),
+            _ => None,
+        })
+        .unwrap();
+    let account = initialize_account.account.as_ref().unwrap();
+    assert_eq!(account.address, address(ACCOUNT));
+    assert_eq!(account.owner, address(OWNER));
+    assert_eq!(account.mint, address(MINT));
+}
@@ -0,0 +1,50 @@
+use proptest::prelude::*;
+
+use substreams_solana_utils::spl_token::TokenInstruction;
+
+use spl_token_substream::token_2022::instruction::Token2022Instruction;
+use spl_token_substream::token_2022::interface::{token_group, token_metadata};
+
+proptest! {
+    #[test]
+    fn unpack_instruction_is_total(data in prop::collection::vec(any::<u8>(), 0..128)) {
+        let _ = TokenInstruction::unpack(&data);
+    }
+
+    #[test]
+    fn unpack_tagged_instruction_is_total(tag in 0u8..32, data in prop::collection::vec(any::<u8>(), 0..128)) {
+        let _ = TokenInstruction::unpack(&[&[tag], data.as_slice()].concat());
+    }
+
+    #[test]
+    fn unpack_token_2022_instruction_is_total(data in prop::collection::vec(any::<u8>(), 0..128)) {
+        let _ = Token2022Instruction::unpack(&data);
+    }
+
+    #[test]
+    fn unpack_extension_instruction_is_total(
+        extension in prop::sample::select(vec![25u8, 26, 27, 28, 29, 30, 32, 33, 34, 35, 36, 37, 39, 40, 43, 44]),
+        tag in 0u8..16,
+        data in prop::collection::vec(any::<u8>(), 0..128),
+    ) {
+        let _ = Token2022Instruction::unpack(&[&[extension, tag], data.as_slice()].concat());
+    }
+
+    #[test]
+    fn unpack_interface_instruction_is_total(
+        discriminator in prop::sample::select(vec![
+            token_metadata::INITIALIZE_DISCRIMINATOR,
+            token_metadata::UPDATE_FIELD_DISCRIMINATOR,
+            token_metadata::REMOVE_KEY_DISCRIMINATOR,
+            token_metadata::UPDATE_AUTHORITY_DISCRIMINATOR,
+            token_metadata::EMIT_DISCRIMINATOR,
+            token_group::INITIALIZE_GROUP_DISCRIMINATOR,
+            token_group::UPDATE_GROUP_MAX_SIZE_DISCRIMINATOR,
+            token_group::UPDATE_GROUP_AUTHORITY_DISCRIMINATOR,
+            token_group::INITIALIZE_MEMBER_DISCRIMINATOR,
+        ]),
+        data in prop::collection::vec(any::<u8>(), 0..128),
+    ) {
+        let _ = Token2022Instruction::unpack(&[discriminator.as_slice(), data.as_slice()].concat());
+    }
+}
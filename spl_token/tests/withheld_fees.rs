@@ -0,0 +1,138 @@
+use substreams_solana::pb::sf::solana::r#type::v1::{ConfirmedTransaction, TransactionStatusMeta};
+
+use solana_substreams_common::pb::solana_common::TokenAccount;
+
+use spl_token_substream::{fill_transaction_events, TOKEN_PROGRAM_IDS};
+use spl_token_substream::pb::spl_token::{
+    ConfidentialTransferEvent, ConfidentialTransferInstructionType, SplTokenEvent, TransferEvent,
+};
+use spl_token_substream::pb::spl_token::spl_token_event::Event;
+
+const SOURCE: &str = "source";
+const DESTINATION: &str = "destination";
+
+fn token_2022() -> String {
+    TOKEN_PROGRAM_IDS[1].to_string()
+}
+
+fn succeeded_transaction() -> ConfirmedTransaction {
+    ConfirmedTransaction {
+        transaction: None,
+        meta: Some(TransactionStatusMeta::default()),
+    }
+}
+
+fn token_account(address: &str, pre_balance: u64, post_balance: u64) -> TokenAccount {
+    TokenAccount {
+        address: address.to_string(),
+        owner: "owner".to_string(),
+        mint: "mint".to_string(),
+        pre_balance: Some(pre_balance),
+        post_balance: Some(post_balance),
+    }
+}
+
+/// A transfer of 100 tokens, of which the destination received `received`.
+fn transfer(token_program: String, received: u64, fee: Option<u64>) -> SplTokenEvent {
+    SplTokenEvent {
+        position: None,
+        token_program,
+        event: Some(Event::Transfer(TransferEvent {
+            source: Some(token_account(SOURCE, 100, 0)),
+            destination: Some(token_account(DESTINATION, 0, received)),
+            authority: "owner".to_string(),
+            amount: 100,
+            fee,
+            ..Default::default()
+        })),
+    }
+}
+
+fn confidential_transfer(instruction_type: ConfidentialTransferInstructionType, account: &str) -> SplTokenEvent {
+    SplTokenEvent {
+        position: None,
+        token_program: token_2022(),
+        event: Some(Event::ConfidentialTransfer(ConfidentialTransferEvent {
+            instruction_type: instruction_type.into(),
+            account: account.to_string(),
+            amount: Some(10),
+            ..Default::default()
+        })),
+    }
+}
+
+fn get_fees(events: &[SplTokenEvent]) -> Vec<Option<u64>> {
+    events.iter().filter_map(|x| match &x.event {
+        Some(Event::Transfer(transfer)) => Some(transfer.fee),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn sole_transfer_gets_the_withheld_fee() {
+    let mut events = vec![transfer(token_2022(), 99, None)];
+    fill_transaction_events(&succeeded_transaction(), &mut events);
+    assert_eq!(get_fees(&events), vec![Some(1)]);
+}
+
+#[test]
+fn fee_is_unset_when_another_event_touches_the_destination() {
+    let mut events = vec![transfer(token_2022(), 99, None), transfer(token_2022(), 99, None)];
+    fill_transaction_events(&succeeded_transaction(), &mut events);
+    assert_eq!(get_fees(&events), vec![None, None]);
+}
+
+#[test]
+fn fee_is_unset_when_the_destination_deposits_or_withdraws() {
+    for instruction_type in [ConfidentialTransferInstructionType::Deposit, ConfidentialTransferInstructionType::Withdraw] {
+        let mut events = vec![transfer(token_2022(), 99, None), confidential_transfer(instruction_type, DESTINATION)];
+        fill_transaction_events(&succeeded_transaction(), &mut events);
+        assert_eq!(get_fees(&events), vec![None]);
+    }
+}
+
+#[test]
+fn confidential_transfers_leave_the_public_balance_alone() {
+    let mut events = vec![
+        transfer(token_2022(), 99, None),
+        confidential_transfer(ConfidentialTransferInstructionType::ApplyPendingBalance, DESTINATION),
+    ];
+    fill_transaction_events(&succeeded_transaction(), &mut events);
+    assert_eq!(get_fees(&events), vec![Some(1)]);
+}
+
+#[test]
+fn fee_is_unset_for_self_transfers() {
+    let mut self_transfer = transfer(token_2022(), 0, None);
+    if let Some(Event::Transfer(transfer)) = &mut self_transfer.event {
+        transfer.source = Some(token_account(DESTINATION, 100, 100));
+        transfer.destination = Some(token_account(DESTINATION, 100, 100));
+    }
+    let mut events = vec![self_transfer];
+    fill_transaction_events(&succeeded_transaction(), &mut events);
+    assert_eq!(get_fees(&events), vec![None]);
+}
+
+#[test]
+fn fee_is_unset_for_the_token_program() {
+    let mut events = vec![transfer(TOKEN_PROGRAM_IDS[0].to_string(), 99, None)];
+    fill_transaction_events(&succeeded_transaction(), &mut events);
+    assert_eq!(get_fees(&events), vec![None]);
+}
+
+#[test]
+fn stated_fee_is_kept() {
+    // A TransferCheckedWithFee states its fee, which is kept over the balance change.
+    let mut events = vec![transfer(token_2022(), 90, Some(1))];
+    fill_transaction_events(&succeeded_transaction(), &mut events);
+    assert_eq!(get_fees(&events), vec![Some(1)]);
+}
+
+#[test]
+fn fee_is_unset_in_failed_transactions() {
+    let mut transaction = succeeded_transaction();
+    transaction.meta.as_mut().unwrap().err = Some(Default::default());
+    let mut events = vec![transfer(token_2022(), 99, None)];
+    fill_transaction_events(&transaction, &mut events);
+    assert_eq!(get_fees(&events), vec![None]);
+}
@@ -0,0 +1,191 @@
+use substreams_solana::pb::sf::solana::r#type::v1::{
+    CompiledInstruction, ConfirmedTransaction, Message, MessageHeader, TokenBalance, Transaction,
+    TransactionStatusMeta, UiTokenAmount,
+};
+
+use solana_substreams_common::failure::ParseFailures;
+use solana_substreams_common::params::ErrorPolicy;
+
+use spl_token_substream::{parse_transaction, NATIVE_MINT, TOKEN_PROGRAM_IDS};
+use spl_token_substream::pb::spl_token::{SplTokenEvent, WrappedSolDirection, WrappedSolEvent};
+use spl_token_substream::pb::spl_token::spl_token_event::Event;
+
+const RENT: u64 = 2_039_280;
+const SOL: u64 = 1_000_000_000;
+
+const OWNER: [u8; 32] = [1; 32];
+const ACCOUNT: [u8; 32] = [2; 32];
+const OTHER_ACCOUNT: [u8; 32] = [3; 32];
+const SYSTEM_PROGRAM: [u8; 32] = [0; 32];
+
+fn address(key: [u8; 32]) -> String {
+    bs58::encode(key).into_string()
+}
+
+/// A wrapped SOL account of `OWNER` at `index` in the account keys, holding `amount`.
+fn native_balance(index: u32, amount: u64) -> TokenBalance {
+    TokenBalance {
+        account_index: index,
+        mint: NATIVE_MINT.to_string(),
+        ui_token_amount: Some(UiTokenAmount {
+            ui_amount: amount as f64 / SOL as f64,
+            decimals: 9,
+            amount: amount.to_string(),
+            ui_amount_string: (amount as f64 / SOL as f64).to_string(),
+        }),
+        owner: address(OWNER),
+        program_id: TOKEN_PROGRAM_IDS[0].to_string(),
+    }
+}
+
+/// A successful transaction signed by `OWNER`, the first of `keys`. `readonly` is the number of
+/// trailing read-only keys, and `instructions` index into `keys` as (program, accounts, data).
+fn transaction(
+    keys: &[[u8; 32]],
+    readonly: u32,
+    instructions: Vec<(u32, Vec<u8>, Vec<u8>)>,
+    pre_balances: Vec<u64>,
+    post_balances: Vec<u64>,
+    pre_token_balances: Vec<TokenBalance>,
+    post_token_balances: Vec<TokenBalance>,
+) -> ConfirmedTransaction {
+    let log_messages = instructions.iter().flat_map(|(program_id_index, _, _)| {
+        let program_id = address(keys[*program_id_index as usize]);
+        vec![
+            format!("Program {} invoke [1]", program_id),
+            format!("Program {} success", program_id),
+        ]
+    }).collect();
+    ConfirmedTransaction {
+        transaction: Some(Transaction {
+            signatures: vec![vec![7; 64]],
+            message: Some(Message {
+                header: Some(MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: readonly,
+                }),
+                account_keys: keys.iter().map(|x| x.to_vec()).collect(),
+                recent_blockhash: vec![9; 32],
+                instructions: instructions.into_iter().map(|(program_id_index, accounts, data)| CompiledInstruction {
+                    program_id_index,
+                    accounts,
+                    data,
+                }).collect(),
+                ..Default::default()
+            }),
+        }),
+        meta: Some(TransactionStatusMeta {
+            fee: 5000,
+            pre_balances,
+            post_balances,
+            pre_token_balances,
+            post_token_balances,
+            log_messages,
+            ..Default::default()
+        }),
+    }
+}
+
+fn system_transfer(lamports: u64) -> Vec<u8> {
+    [&2u32.to_le_bytes()[..], &lamports.to_le_bytes()].concat()
+}
+
+fn token_transfer(amount: u64) -> Vec<u8> {
+    [&[3u8][..], &amount.to_le_bytes()].concat()
+}
+
+fn parse(transaction: &ConfirmedTransaction) -> Vec<SplTokenEvent> {
+    parse_transaction(transaction, &mut ParseFailures::new(ErrorPolicy::Strict)).unwrap()
+}
+
+fn get_wrapped_sol(events: &[SplTokenEvent]) -> Vec<WrappedSolEvent> {
+    events.iter().filter_map(|x| match &x.event {
+        Some(Event::WrappedSol(wrapped_sol)) => Some(wrapped_sol.clone()),
+        _ => None,
+    }).collect()
+}
+
+#[test]
+fn sync_native_wraps_the_transferred_lamports() {
+    let keys = [OWNER, ACCOUNT, SYSTEM_PROGRAM, TOKEN_PROGRAM_IDS[0].0];
+    let transaction = transaction(
+        &keys,
+        2,
+        vec![
+            (2, vec![0, 1], system_transfer(SOL)),
+            (3, vec![1], vec![17]),
+        ],
+        vec![10 * SOL, RENT, 1, 1],
+        vec![9 * SOL - 5000, RENT + SOL, 1, 1],
+        vec![native_balance(1, 0)],
+        vec![native_balance(1, SOL)],
+    );
+    let events = parse(&transaction);
+
+    let sync_native = events.iter().find_map(|x| match &x.event {
+        Some(Event::SyncNative(sync_native)) => Some(sync_native),
+        _ => None,
+    }).unwrap();
+    assert_eq!(sync_native.lamports, Some(SOL));
+
+    let wrapped_sol = get_wrapped_sol(&events);
+    assert_eq!(wrapped_sol.len(), 1);
+    assert_eq!(wrapped_sol[0].direction, WrappedSolDirection::Wrap as i32);
+    assert_eq!(wrapped_sol[0].lamports, SOL);
+    assert_eq!(wrapped_sol[0].funding_accounts, vec![address(OWNER)]);
+}
+
+#[test]
+fn initializing_a_funded_account_wraps_nothing() {
+    // The lamports were sent before the account was initialized, so its initial amount already
+    // reflects them.
+    let keys = [OWNER, ACCOUNT, NATIVE_MINT.0, TOKEN_PROGRAM_IDS[0].0];
+    let transaction = transaction(
+        &keys,
+        2,
+        vec![
+            (3, vec![1, 2], [&[18u8][..], &OWNER].concat()),
+            (3, vec![1], vec![17]),
+        ],
+        vec![10 * SOL, RENT + SOL, 1, 1],
+        vec![10 * SOL - 5000, RENT + SOL, 1, 1],
+        vec![],
+        vec![native_balance(1, SOL)],
+    );
+    let events = parse(&transaction);
+
+    assert!(events.iter().any(|x| matches!(x.event, Some(Event::InitializeAccount(_)))));
+    assert_eq!(get_wrapped_sol(&events), vec![]);
+}
+
+#[test]
+fn close_account_unwraps_the_transferred_lamports() {
+    let keys = [OWNER, ACCOUNT, OTHER_ACCOUNT, TOKEN_PROGRAM_IDS[0].0];
+    let transaction = transaction(
+        &keys,
+        1,
+        vec![
+            (3, vec![1, 2, 0], token_transfer(SOL)),
+            (3, vec![2, 0, 0], vec![9]),
+        ],
+        vec![10 * SOL, RENT + SOL, RENT, 1],
+        vec![10 * SOL + RENT + SOL - 5000, RENT, 0, 1],
+        vec![native_balance(1, SOL), native_balance(2, 0)],
+        vec![native_balance(1, 0)],
+    );
+    let events = parse(&transaction);
+
+    let close_account = events.iter().find_map(|x| match &x.event {
+        Some(Event::CloseAccount(close_account)) => Some(close_account),
+        _ => None,
+    }).unwrap();
+    assert_eq!(close_account.lamports, Some(RENT + SOL));
+
+    let wrapped_sol = get_wrapped_sol(&events);
+    assert_eq!(wrapped_sol.len(), 1);
+    assert_eq!(wrapped_sol[0].direction, WrappedSolDirection::Unwrap as i32);
+    assert_eq!(wrapped_sol[0].lamports, RENT + SOL);
+    assert_eq!(wrapped_sol[0].account.as_ref().map(|x| x.address.clone()), Some(address(OTHER_ACCOUNT)));
+    assert_eq!(wrapped_sol[0].destination, Some(address(OWNER)));
+}
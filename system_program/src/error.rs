@@ -0,0 +1,58 @@
+use thiserror::Error;
+
+/// Errors raised by the System program, as returned in `InstructionError::Custom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemError {
+    /// An account with the same address already exists.
+    AccountAlreadyInUse = 0,
+    /// Account does not have enough SOL to perform the operation.
+    ResultWithNegativeLamports = 1,
+    /// Cannot assign account to this program id.
+    InvalidProgramId = 2,
+    /// Cannot allocate account data of this length.
+    InvalidAccountDataLength = 3,
+    /// Length of requested seed is too long.
+    MaxSeedLengthExceeded = 4,
+    /// Provided address does not match addressed derived from seed.
+    AddressWithSeedMismatch = 5,
+    /// Advancing stored nonce requires a populated RecentBlockhashes sysvar.
+    NonceNoRecentBlockhashes = 6,
+    /// Stored nonce is still in recent_blockhashes.
+    NonceBlockhashNotExpired = 7,
+    /// Specified nonce does not match stored nonce.
+    NonceUnexpectedBlockhashValue = 8,
+}
+
+impl SystemError {
+    pub fn from_u32(code: u32) -> Option<Self> {
+        match code {
+            0 => Some(SystemError::AccountAlreadyInUse),
+            1 => Some(SystemError::ResultWithNegativeLamports),
+            2 => Some(SystemError::InvalidProgramId),
+            3 => Some(SystemError::InvalidAccountDataLength),
+            4 => Some(SystemError::MaxSeedLengthExceeded),
+            5 => Some(SystemError::AddressWithSeedMismatch),
+            6 => Some(SystemError::NonceNoRecentBlockhashes),
+            7 => Some(SystemError::NonceBlockhashNotExpired),
+            8 => Some(SystemError::NonceUnexpectedBlockhashValue),
+            _ => None,
+        }
+    }
+
+    pub fn name(code: u32) -> Option<String> {
+        Self::from_u32(code).map(|x| format!("{:?}", x))
+    }
+}
+
+/// Errors raised while parsing a System program instruction.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("Not a System program instruction")]
+    InvalidProgram,
+    #[error("Failed to unpack System instruction: {0}")]
+    Unpack(String),
+    #[error("Missing account at index {0}")]
+    MissingAccount(usize),
+    #[error("Expected a {0} instruction")]
+    UnexpectedInstruction(&'static str),
+}
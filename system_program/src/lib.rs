@@ -1,57 +1,98 @@
-use anyhow::anyhow;
-use anyhow::Context;
 use substreams::errors::Error;
 use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::pb::sf::solana::r#type::v1::Block;
 
 use substreams_solana_utils as utils;
 use utils::transaction::{get_context, TransactionContext};
-use utils::instruction::{get_structured_instructions, StructuredInstructions, StructuredInstruction};
+use utils::instruction::{get_structured_instructions, StructuredInstruction};
 use utils::system_program::{self, SystemInstruction, SYSTEM_PROGRAM_ID};
 use utils::pubkey::Pubkey;
 
+use solana_substreams_common::account::get_account_balance;
+use solana_substreams_common::block;
+use solana_substreams_common::envelope::get_block_envelope;
+use solana_substreams_common::instruction::get_positioned_instructions;
+use solana_substreams_common::failure::ParseFailures;
+use solana_substreams_common::params::Params;
+use solana_substreams_common::pb::solana_common::TransactionError;
+use solana_substreams_common::transaction_error::get_transaction_error;
+
 pub mod pb;
 use pb::system_program::*;
 use pb::system_program::system_program_event::Event;
 
+pub mod error;
+use error::{ParseError, SystemError};
+
 #[substreams::handlers::map]
-fn system_program_events(block: Block) -> Result<SystemProgramBlockEvents, Error> {
-    let transactions = parse_block(&block)?;
-    Ok(SystemProgramBlockEvents { slot: block.slot, transactions })
-}
-
-pub fn parse_block(block: &Block) -> Result<Vec<SystemProgramTransactionEvents>, Error> {
-    let mut block_events: Vec<SystemProgramTransactionEvents> = Vec::new();
-    for (i, transaction) in block.transactions.iter().enumerate() {
-        let events = parse_transaction(transaction)?;
-        if !events.is_empty() {
-            block_events.push(SystemProgramTransactionEvents {
-                signature: utils::transaction::get_signature(transaction),
-                transaction_index: i as u32,
-                events,
-            });
-        }
-    }
-    Ok(block_events)
+fn system_program_events(params: String, block: Block) -> Result<SystemProgramBlockEvents, Error> {
+    get_block_events(&block, &Params::parse(&params)?)
 }
 
-pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<SystemProgramEvent>, Error> {
-    if let Some(_) = transaction.meta.as_ref().unwrap().err {
+pub fn get_block_events(block: &Block, params: &Params) -> Result<SystemProgramBlockEvents, Error> {
+    let mut failures = ParseFailures::new(params.error_policy);
+    let transactions = parse_block(block, params, &mut failures)?;
+    Ok(SystemProgramBlockEvents {
+        slot: block.slot,
+        transactions,
+        envelope: Some(get_block_envelope(block)),
+        parse_failure_count: failures.count(),
+        parse_failures: failures.into_failures(),
+    })
+}
+
+pub fn parse_block(block: &Block, params: &Params, failures: &mut ParseFailures) -> Result<Vec<SystemProgramTransactionEvents>, Error> {
+    let transactions = block::parse_block(
+        block,
+        params,
+        failures,
+        &[SYSTEM_PROGRAM_ID.to_string()],
+        get_system_program_transaction_error,
+        _parse_transaction,
+    )?;
+    Ok(transactions.into_iter().map(|x| SystemProgramTransactionEvents {
+        signature: x.signature,
+        transaction_index: x.envelope.transaction_index,
+        events: x.events,
+        envelope: Some(x.envelope),
+        error: x.error,
+    }).collect())
+}
+
+/// Parses a successful transaction, handing instructions that fail to parse to `failures`.
+pub fn parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<SystemProgramEvent>, Error> {
+    if transaction.meta.as_ref().map_or(true, |meta| meta.err.is_some()) {
         return Ok(Vec::new())
     }
+    _parse_transaction(transaction, failures)
+}
 
+/// Parses the instructions of a failed transaction up to the point of failure. Instructions
+/// that cannot be parsed are handed to `failures`, but don't fail the block under the `strict`
+/// policy.
+pub fn parse_failed_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<SystemProgramEvent>, Error> {
+    failures.without_failing(|failures| _parse_transaction(transaction, failures))
+}
+
+fn _parse_transaction(transaction: &ConfirmedTransaction, failures: &mut ParseFailures) -> Result<Vec<SystemProgramEvent>, Error> {
     let mut events: Vec<SystemProgramEvent> = Vec::new();
 
-    let context = get_context(transaction)?;
-    let instructions = get_structured_instructions(transaction)?;
+    let context = match get_context(transaction) {
+        Ok(context) => context,
+        Err(error) => return failures.record(&utils::transaction::get_signature(transaction), None, error).map(|_| events),
+    };
+    let instructions = match get_structured_instructions(transaction) {
+        Ok(instructions) => instructions,
+        Err(error) => return failures.record(&context.signature, None, error).map(|_| events),
+    };
 
-    for (i, instruction) in instructions.flattened().iter().enumerate() {
+    for (i, (position, instruction)) in get_positioned_instructions(&instructions).into_iter().enumerate() {
         if instruction.program_id() == SYSTEM_PROGRAM_ID {
-            match parse_instruction(instruction, &context) {
+            match parse_instruction(&instruction, &context) {
                 Ok(event) => {
-                    events.push(SystemProgramEvent { instruction_index: i as u32, event });
+                    events.push(SystemProgramEvent { instruction_index: i as u32, position: Some(position), event });
                 },
-                Err(e) => return Err(anyhow!("Failed to parse transaction {} with error: {}", context.signature, e))
+                Err(error) => failures.record(&context.signature, Some(position), error)?,
             }
         }
     }
@@ -59,14 +100,21 @@ pub fn parse_transaction(transaction: &ConfirmedTransaction) -> Result<Vec<Syste
     Ok(events)
 }
 
+/// Decodes the transaction error, naming it when raised by the System program.
+pub fn get_system_program_transaction_error(transaction: &ConfirmedTransaction) -> Option<TransactionError> {
+    let mut error = get_transaction_error(transaction)?;
+    error.name_custom_error(&SYSTEM_PROGRAM_ID.to_string(), SystemError::name);
+    Some(error)
+}
+
 pub fn parse_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext
-) -> Result<Option<Event>, Error> {
+) -> Result<Option<Event>, ParseError> {
     if instruction.program_id() != SYSTEM_PROGRAM_ID {
-        return Err(anyhow!("Not a System Program instruction."));
+        return Err(ParseError::InvalidProgram);
     }
-    let unpacked = SystemInstruction::unpack(&instruction.data())?;
+    let unpacked = SystemInstruction::unpack(&instruction.data()).map_err(|x| ParseError::Unpack(x.to_string()))?;
     match unpacked {
         SystemInstruction::CreateAccount(create_account) => {
             _parse_create_account_instruction(instruction, context, &create_account).map(|x| Some(Event::CreateAccount(x)))
@@ -107,16 +155,16 @@ pub fn parse_instruction<'a>(
         SystemInstruction::UpgradeNonceAccount => {
             _parse_upgrade_nonce_account_instruction(instruction, context).map(|x| Some(Event::UpgradeNonceAccount(x)))
         }
-    }.context("Failed to parse System instruction")
+    }
 }
 
 fn _parse_create_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     create_account: &system_program::CreateAccount,
-) -> Result<CreateAccountEvent, Error> {
-    let funding_account = instruction.accounts()[0].to_string();
-    let new_account = instruction.accounts()[1].to_string();
+) -> Result<CreateAccountEvent, ParseError> {
+    let funding_account = get_account(instruction, 0)?;
+    let new_account = get_account(instruction, 1)?;
     let lamports = create_account.lamports;
     let owner = create_account.owner.to_string();
     let space = create_account.space;
@@ -134,8 +182,8 @@ fn _parse_assign_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     assign: &system_program::Assign,
-) -> Result<AssignEvent, Error> {
-    let assigned_account = instruction.accounts()[0].to_string();
+) -> Result<AssignEvent, ParseError> {
+    let assigned_account = get_account(instruction, 0)?;
     let owner = assign.owner.to_string();
 
     Ok(AssignEvent {
@@ -148,12 +196,12 @@ fn _parse_transfer_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
     transfer: &system_program::Transfer,
-) -> Result<TransferEvent, Error> {
-    let funding_account = instruction.accounts()[0].to_string();
-    let recipient_account = instruction.accounts()[1].to_string();
+) -> Result<TransferEvent, ParseError> {
+    let funding_account = get_account(instruction, 0)?;
+    let recipient_account = get_account(instruction, 1)?;
     let lamports = transfer.lamports;
-    let funding_account_balance = context.account_balances.get(instruction.instruction.accounts()[0] as usize).map(|x| x.clone().into());
-    let recipient_account_balance = context.account_balances.get(instruction.instruction.accounts()[1] as usize).map(|x| x.clone().into());
+    let funding_account_balance = get_account_balance(instruction, context, 0);
+    let recipient_account_balance = get_account_balance(instruction, context, 1);
 
     Ok(TransferEvent {
         funding_account,
@@ -168,9 +216,9 @@ fn _parse_create_account_with_seed_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     create_account_with_seed: &system_program::CreateAccountWithSeed,
-) -> Result<CreateAccountWithSeedEvent, Error> {
-    let funding_account = instruction.accounts()[0].to_string();
-    let created_account = instruction.accounts()[1].to_string();
+) -> Result<CreateAccountWithSeedEvent, ParseError> {
+    let funding_account = get_account(instruction, 0)?;
+    let created_account = get_account(instruction, 1)?;
     let base_account = create_account_with_seed.base.to_string();
     let lamports = create_account_with_seed.lamports;
     let owner = create_account_with_seed.owner.to_string();
@@ -191,9 +239,9 @@ fn _parse_create_account_with_seed_instruction(
 fn _parse_advance_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-) -> Result<AdvanceNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
-    let nonce_authority = instruction.accounts()[2].to_string();
+) -> Result<AdvanceNonceAccountEvent, ParseError> {
+    let nonce_account = get_account(instruction, 0)?;
+    let nonce_authority = get_account(instruction, 2)?;
 
     Ok(AdvanceNonceAccountEvent {
         nonce_account,
@@ -205,10 +253,10 @@ fn _parse_withdraw_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     lamports: u64,
-) -> Result<WithdrawNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
-    let recipient_account = instruction.accounts()[1].to_string();
-    let nonce_authority = instruction.accounts()[4].to_string();
+) -> Result<WithdrawNonceAccountEvent, ParseError> {
+    let nonce_account = get_account(instruction, 0)?;
+    let recipient_account = get_account(instruction, 1)?;
+    let nonce_authority = get_account(instruction, 4)?;
 
     Ok(WithdrawNonceAccountEvent {
         nonce_account,
@@ -222,8 +270,8 @@ fn _parse_initialize_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     authority: Pubkey,
-) -> Result<InitializeNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
+) -> Result<InitializeNonceAccountEvent, ParseError> {
+    let nonce_account = get_account(instruction, 0)?;
     let nonce_authority = authority.to_string();
 
     Ok(InitializeNonceAccountEvent {
@@ -236,9 +284,9 @@ fn _parse_authorize_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     pubkey: Pubkey,
-) -> Result<AuthorizeNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
-    let nonce_authority = instruction.accounts()[1].to_string();
+) -> Result<AuthorizeNonceAccountEvent, ParseError> {
+    let nonce_account = get_account(instruction, 0)?;
+    let nonce_authority = get_account(instruction, 1)?;
     let new_nonce_authority = pubkey.to_string();
 
     Ok(AuthorizeNonceAccountEvent {
@@ -252,8 +300,8 @@ fn _parse_allocate_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     allocate: &system_program::Allocate,
-) -> Result<AllocateEvent, Error> {
-    let account = instruction.accounts()[0].to_string();
+) -> Result<AllocateEvent, ParseError> {
+    let account = get_account(instruction, 0)?;
     let space = allocate.space;
 
     Ok(AllocateEvent {
@@ -266,8 +314,8 @@ fn _parse_allocate_with_seed_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     allocate_with_seed: &system_program::AllocateWithSeed,
-) -> Result<AllocateWithSeedEvent, Error> {
-    let allocated_account = instruction.accounts()[0].to_string();
+) -> Result<AllocateWithSeedEvent, ParseError> {
+    let allocated_account = get_account(instruction, 0)?;
     let space = allocate_with_seed.space;
     let base_account = allocate_with_seed.base.to_string();
     let owner = allocate_with_seed.owner.to_string();
@@ -286,8 +334,8 @@ fn _parse_assign_with_seed_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
     assign_with_seed: &system_program::AssignWithSeed,
-) -> Result<AssignWithSeedEvent, Error> {
-    let assigned_account = instruction.accounts()[0].to_string();
+) -> Result<AssignWithSeedEvent, ParseError> {
+    let assigned_account = get_account(instruction, 0)?;
     let base_account = assign_with_seed.base.to_string();
     let owner = assign_with_seed.owner.to_string();
     let seed = assign_with_seed.seed.0.clone();
@@ -304,15 +352,15 @@ fn _parse_transfer_with_seed_instruction(
     instruction: &StructuredInstruction,
     context: &TransactionContext,
     transfer_with_seed: system_program::TransferWithSeed
-) -> Result<TransferWithSeedEvent, Error> {
-    let funding_account = instruction.accounts()[0].to_string();
-    let base_account = instruction.accounts()[1].to_string();
-    let recipient_account = instruction.accounts()[2].to_string();
+) -> Result<TransferWithSeedEvent, ParseError> {
+    let funding_account = get_account(instruction, 0)?;
+    let base_account = get_account(instruction, 1)?;
+    let recipient_account = get_account(instruction, 2)?;
     let from_owner = transfer_with_seed.from_owner.to_string();
     let from_seed = transfer_with_seed.from_seed.0.clone();
     let lamports = transfer_with_seed.lamports;
-    let funding_account_balance = context.account_balances.get(instruction.instruction.accounts()[0] as usize).map(|x| x.clone().into());
-    let recipient_account_balance = context.account_balances.get(instruction.instruction.accounts()[1] as usize).map(|x| x.clone().into());
+    let funding_account_balance = get_account_balance(instruction, context, 0);
+    let recipient_account_balance = get_account_balance(instruction, context, 1);
 
     Ok(TransferWithSeedEvent {
         funding_account,
@@ -329,149 +377,144 @@ fn _parse_transfer_with_seed_instruction(
 fn _parse_upgrade_nonce_account_instruction(
     instruction: &StructuredInstruction,
     _context: &TransactionContext,
-) -> Result<UpgradeNonceAccountEvent, Error> {
-    let nonce_account = instruction.accounts()[0].to_string();
+) -> Result<UpgradeNonceAccountEvent, ParseError> {
+    let nonce_account = get_account(instruction, 0)?;
 
     Ok(UpgradeNonceAccountEvent {
         nonce_account,
     })
 }
 
+fn get_account(instruction: &StructuredInstruction, index: usize) -> Result<String, ParseError> {
+    instruction.accounts().get(index).map(|x| x.to_string()).ok_or(ParseError::MissingAccount(index))
+}
+
 pub fn parse_create_account_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<CreateAccountEvent, Error> {
+) -> Result<CreateAccountEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::CreateAccount(event)) => Ok(event),
-        _ => Err(anyhow!("Not a CreateAccountInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("CreateAccount"))
     }
 }
 
 pub fn parse_assign_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<AssignEvent, Error> {
+) -> Result<AssignEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::Assign(event)) => Ok(event),
-        _ => Err(anyhow!("Not an AssignInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("Assign"))
     }
 }
 
 pub fn parse_transfer_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<TransferEvent, Error> {
+) -> Result<TransferEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::Transfer(event)) => Ok(event),
-        _ => Err(anyhow!("Not a TransferInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("Transfer"))
     }
 }
 
 pub fn parse_create_account_with_seed_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<CreateAccountWithSeedEvent, Error> {
+) -> Result<CreateAccountWithSeedEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::CreateAccountWithSeed(event)) => Ok(event),
-        _ => Err(anyhow!("Not a CreateAccountWithSeedInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("CreateAccountWithSeed"))
     }
 }
 
 pub fn parse_advance_nonce_account_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<AdvanceNonceAccountEvent, Error> {
+) -> Result<AdvanceNonceAccountEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::AdvanceNonceAccount(event)) => Ok(event),
-        _ => Err(anyhow!("Not an AdvanceNonceAccountInstruction.")),
+        _ => Err(ParseError::UnexpectedInstruction("AdvanceNonceAccount")),
     }
 }
 
 pub fn parse_withdraw_nonce_account_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<WithdrawNonceAccountEvent, Error> {
+) -> Result<WithdrawNonceAccountEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::WithdrawNonceAccount(event)) => Ok(event),
-        _ => Err(anyhow!("Not a WithdrawNonceAccountInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("WithdrawNonceAccount"))
     }
 }
 
 pub fn parse_initialize_nonce_account_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<InitializeNonceAccountEvent, Error> {
+) -> Result<InitializeNonceAccountEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::InitializeNonceAccount(event)) => Ok(event),
-        _ => Err(anyhow!("Not an InitializeNonceAccountInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("InitializeNonceAccount"))
     }
 }
 
 pub fn parse_authorize_nonce_account_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<AuthorizeNonceAccountEvent, Error> {
+) -> Result<AuthorizeNonceAccountEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::AuthorizeNonceAccount(event)) => Ok(event),
-        _ => Err(anyhow!("Not an AuthorizeNonceAccountInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("AuthorizeNonceAccount"))
     }
 }
 
 pub fn parse_allocate_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<AllocateEvent, Error> {
+) -> Result<AllocateEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::Allocate(event)) => Ok(event),
-        _ => Err(anyhow!("Not an AllocateInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("Allocate"))
     }
 }
 
 pub fn parse_allocate_with_seed_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<AllocateWithSeedEvent, Error> {
+) -> Result<AllocateWithSeedEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::AllocateWithSeed(event)) => Ok(event),
-        _ => Err(anyhow!("Not an AllocateWithSeedInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("AllocateWithSeed"))
     }
 }
 
 pub fn parse_assign_with_seed_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<AssignWithSeedEvent, Error> {
+) -> Result<AssignWithSeedEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::AssignWithSeed(event)) => Ok(event),
-        _ => Err(anyhow!("Not an AssignWithSeedInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("AssignWithSeed"))
     }
 }
 
 pub fn parse_transfer_with_seed_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<TransferWithSeedEvent, Error> {
+) -> Result<TransferWithSeedEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::TransferWithSeed(event)) => Ok(event),
-        _ => Err(anyhow!("Not a TransferWithSeedInstruction."))
+        _ => Err(ParseError::UnexpectedInstruction("TransferWithSeed"))
     }
 }
 
 pub fn parse_upgrade_nonce_account_instruction<'a>(
     instruction: &StructuredInstruction<'a>,
     context: &TransactionContext,
-) -> Result<UpgradeNonceAccountEvent, Error> {
+) -> Result<UpgradeNonceAccountEvent, ParseError> {
     match parse_instruction(instruction, context)? {
         Some(Event::UpgradeNonceAccount(event)) => Ok(event),
-        _ => Err(anyhow!("Not an UpgradeNonceAccountInstruction."))
-    }
-}
-
-impl From<utils::account::AccountBalance> for AccountBalance {
-    fn from(value: utils::account::AccountBalance) -> Self {
-        Self {
-            pre_balance: value.pre_balance,
-            post_balance: value.post_balance,
-        }
+        _ => Err(ParseError::UnexpectedInstruction("UpgradeNonceAccount"))
     }
 }
@@ -3,6 +3,7 @@
 /// the Solana Labs repositories.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Block {
     #[prost(string, tag="1")]
     pub previous_blockhash: ::prost::alloc::string::String,
@@ -24,6 +25,7 @@ pub struct Block {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct ConfirmedTransaction {
     #[prost(message, optional, tag="1")]
     pub transaction: ::core::option::Option<Transaction>,
@@ -32,6 +34,7 @@ pub struct ConfirmedTransaction {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Transaction {
     #[prost(bytes="vec", repeated, tag="1")]
     pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
@@ -40,6 +43,7 @@ pub struct Transaction {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Message {
     #[prost(message, optional, tag="1")]
     pub header: ::core::option::Option<MessageHeader>,
@@ -56,6 +60,7 @@ pub struct Message {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MessageHeader {
     #[prost(uint32, tag="1")]
     pub num_required_signatures: u32,
@@ -66,6 +71,7 @@ pub struct MessageHeader {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct MessageAddressTableLookup {
     #[prost(bytes="vec", tag="1")]
     pub account_key: ::prost::alloc::vec::Vec<u8>,
@@ -76,6 +82,7 @@ pub struct MessageAddressTableLookup {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct TransactionStatusMeta {
     #[prost(message, optional, tag="1")]
     pub err: ::core::option::Option<TransactionError>,
@@ -115,12 +122,14 @@ pub struct TransactionStatusMeta {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct TransactionError {
     #[prost(bytes="vec", tag="1")]
     pub err: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct InnerInstructions {
     #[prost(uint32, tag="1")]
     pub index: u32,
@@ -129,6 +138,7 @@ pub struct InnerInstructions {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct InnerInstruction {
     #[prost(uint32, tag="1")]
     pub program_id_index: u32,
@@ -144,6 +154,7 @@ pub struct InnerInstruction {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CompiledInstruction {
     #[prost(uint32, tag="1")]
     pub program_id_index: u32,
@@ -154,6 +165,7 @@ pub struct CompiledInstruction {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct TokenBalance {
     #[prost(uint32, tag="1")]
     pub account_index: u32,
@@ -168,6 +180,7 @@ pub struct TokenBalance {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UiTokenAmount {
     #[prost(double, tag="1")]
     pub ui_amount: f64,
@@ -180,6 +193,7 @@ pub struct UiTokenAmount {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct ReturnData {
     #[prost(bytes="vec", tag="1")]
     pub program_id: ::prost::alloc::vec::Vec<u8>,
@@ -188,6 +202,7 @@ pub struct ReturnData {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Reward {
     #[prost(string, tag="1")]
     pub pubkey: ::prost::alloc::string::String,
@@ -202,23 +217,27 @@ pub struct Reward {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Rewards {
     #[prost(message, repeated, tag="1")]
     pub rewards: ::prost::alloc::vec::Vec<Reward>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UnixTimestamp {
     #[prost(int64, tag="1")]
     pub timestamp: i64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct BlockHeight {
     #[prost(uint64, tag="1")]
     pub block_height: u64,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 #[repr(i32)]
 pub enum RewardType {
     Unspecified = 0,
@@ -1,12 +1,14 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Instructions {
     #[prost(message, repeated, tag="1")]
     pub instructions: ::prost::alloc::vec::Vec<Instruction>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Instruction {
     #[prost(string, tag="1")]
     pub program_id: ::prost::alloc::string::String,
@@ -1,6 +1,7 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct Transactions {
     #[prost(message, repeated, tag="1")]
     pub transactions: ::prost::alloc::vec::Vec<super::super::super::sf::solana::r#type::v1::ConfirmedTransaction>,
@@ -1,14 +1,23 @@
 // @generated
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SystemProgramBlockEvents {
     #[prost(uint64, tag="1")]
     pub slot: u64,
     #[prost(message, repeated, tag="2")]
     pub transactions: ::prost::alloc::vec::Vec<SystemProgramTransactionEvents>,
+    #[prost(message, optional, tag="3")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::BlockEnvelope>,
+    /// Instructions that failed to parse. Only populated with `error_policy=report`.
+    #[prost(message, repeated, tag="4")]
+    pub parse_failures: ::prost::alloc::vec::Vec<::solana_substreams_common::pb::solana_common::ParseFailure>,
+    #[prost(uint32, tag="5")]
+    pub parse_failure_count: u32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SystemProgramTransactionEvents {
     #[prost(string, tag="1")]
     pub signature: ::prost::alloc::string::String,
@@ -16,12 +25,21 @@ pub struct SystemProgramTransactionEvents {
     pub transaction_index: u32,
     #[prost(message, repeated, tag="3")]
     pub events: ::prost::alloc::vec::Vec<SystemProgramEvent>,
+    #[prost(message, optional, tag="4")]
+    pub envelope: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionEnvelope>,
+    /// Set on failed transactions, which are only included when `include_failed_transactions` is enabled.
+    #[prost(message, optional, tag="5")]
+    pub error: ::core::option::Option<::solana_substreams_common::pb::solana_common::TransactionError>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct SystemProgramEvent {
+    /// Deprecated: index into the flattened instruction list, use `position` instead.
     #[prost(uint32, tag="1")]
     pub instruction_index: u32,
+    #[prost(message, optional, tag="15")]
+    pub position: ::core::option::Option<::solana_substreams_common::pb::solana_common::InstructionPosition>,
     #[prost(oneof="system_program_event::Event", tags="2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14")]
     pub event: ::core::option::Option<system_program_event::Event>,
 }
@@ -29,6 +47,7 @@ pub struct SystemProgramEvent {
 pub mod system_program_event {
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
     pub enum Event {
         #[prost(message, tag="2")]
         CreateAccount(super::CreateAccountEvent),
@@ -60,6 +79,7 @@ pub mod system_program_event {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateAccountEvent {
     #[prost(string, tag="1")]
     pub funding_account: ::prost::alloc::string::String,
@@ -74,6 +94,7 @@ pub struct CreateAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct AssignEvent {
     #[prost(string, tag="1")]
     pub assigned_account: ::prost::alloc::string::String,
@@ -82,6 +103,7 @@ pub struct AssignEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct TransferEvent {
     #[prost(string, tag="1")]
     pub funding_account: ::prost::alloc::string::String,
@@ -90,12 +112,13 @@ pub struct TransferEvent {
     #[prost(uint64, tag="3")]
     pub lamports: u64,
     #[prost(message, optional, tag="4")]
-    pub funding_account_balance: ::core::option::Option<AccountBalance>,
+    pub funding_account_balance: ::core::option::Option<::solana_substreams_common::pb::solana_common::AccountBalance>,
     #[prost(message, optional, tag="5")]
-    pub recipient_account_balance: ::core::option::Option<AccountBalance>,
+    pub recipient_account_balance: ::core::option::Option<::solana_substreams_common::pb::solana_common::AccountBalance>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct CreateAccountWithSeedEvent {
     #[prost(string, tag="1")]
     pub funding_account: ::prost::alloc::string::String,
@@ -114,6 +137,7 @@ pub struct CreateAccountWithSeedEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct AdvanceNonceAccountEvent {
     #[prost(string, tag="1")]
     pub nonce_account: ::prost::alloc::string::String,
@@ -122,6 +146,7 @@ pub struct AdvanceNonceAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct WithdrawNonceAccountEvent {
     #[prost(string, tag="1")]
     pub nonce_account: ::prost::alloc::string::String,
@@ -134,6 +159,7 @@ pub struct WithdrawNonceAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct InitializeNonceAccountEvent {
     #[prost(string, tag="1")]
     pub nonce_account: ::prost::alloc::string::String,
@@ -142,6 +168,7 @@ pub struct InitializeNonceAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct AuthorizeNonceAccountEvent {
     #[prost(string, tag="1")]
     pub nonce_account: ::prost::alloc::string::String,
@@ -152,6 +179,7 @@ pub struct AuthorizeNonceAccountEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct AllocateEvent {
     #[prost(string, tag="1")]
     pub account: ::prost::alloc::string::String,
@@ -160,6 +188,7 @@ pub struct AllocateEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct AllocateWithSeedEvent {
     #[prost(string, tag="1")]
     pub allocated_account: ::prost::alloc::string::String,
@@ -174,6 +203,7 @@ pub struct AllocateWithSeedEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct AssignWithSeedEvent {
     #[prost(string, tag="1")]
     pub assigned_account: ::prost::alloc::string::String,
@@ -186,6 +216,7 @@ pub struct AssignWithSeedEvent {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct TransferWithSeedEvent {
     #[prost(string, tag="1")]
     pub funding_account: ::prost::alloc::string::String,
@@ -200,22 +231,15 @@ pub struct TransferWithSeedEvent {
     #[prost(string, tag="6")]
     pub from_owner: ::prost::alloc::string::String,
     #[prost(message, optional, tag="7")]
-    pub funding_account_balance: ::core::option::Option<AccountBalance>,
+    pub funding_account_balance: ::core::option::Option<::solana_substreams_common::pb::solana_common::AccountBalance>,
     #[prost(message, optional, tag="8")]
-    pub recipient_account_balance: ::core::option::Option<AccountBalance>,
+    pub recipient_account_balance: ::core::option::Option<::solana_substreams_common::pb::solana_common::AccountBalance>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+#[cfg_attr(feature = "json", derive(::serde::Serialize))]
 pub struct UpgradeNonceAccountEvent {
     #[prost(string, tag="1")]
     pub nonce_account: ::prost::alloc::string::String,
 }
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AccountBalance {
-    #[prost(uint64, tag="1")]
-    pub pre_balance: u64,
-    #[prost(uint64, tag="2")]
-    pub post_balance: u64,
-}
 // @@protoc_insertion_point(module)
@@ -0,0 +1,74 @@
+use substreams_solana::pb::sf::solana::r#type::v1::{
+    Block, CompiledInstruction, ConfirmedTransaction, Message, MessageHeader, Transaction, TransactionStatusMeta,
+};
+
+use substreams_solana_utils::system_program::SYSTEM_PROGRAM_ID;
+
+use solana_substreams_common::params::{ErrorPolicy, Params};
+
+use system_program_substream::get_block_events;
+
+const PAYER: [u8; 32] = [1; 32];
+const SYSTEM_PROGRAM: [u8; 32] = [0; 32];
+
+/// A block with a single failed transaction, whose System instruction can't be unpacked.
+fn block() -> Block {
+    let transaction = ConfirmedTransaction {
+        transaction: Some(Transaction {
+            signatures: vec![vec![7; 64]],
+            message: Some(Message {
+                header: Some(MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 1,
+                }),
+                account_keys: vec![PAYER.to_vec(), SYSTEM_PROGRAM.to_vec()],
+                recent_blockhash: vec![9; 32],
+                instructions: vec![CompiledInstruction {
+                    program_id_index: 1,
+                    accounts: vec![0],
+                    data: vec![255; 4],
+                }],
+                ..Default::default()
+            }),
+        }),
+        meta: Some(TransactionStatusMeta {
+            err: Some(Default::default()),
+            fee: 5000,
+            pre_balances: vec![10_000, 1],
+            post_balances: vec![5000, 1],
+            log_messages: vec![
+                format!("Program {} invoke [1]", SYSTEM_PROGRAM_ID),
+                format!("Program {} failed: invalid instruction data", SYSTEM_PROGRAM_ID),
+            ],
+            ..Default::default()
+        }),
+    };
+    Block {
+        slot: 1,
+        transactions: vec![transaction],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn failures_of_failed_transactions_are_reported() {
+    let params = Params {
+        include_failed_transactions: true,
+        error_policy: ErrorPolicy::Report,
+    };
+    let events = get_block_events(&block(), &params).unwrap();
+    assert_eq!(events.parse_failure_count, 1);
+    assert_eq!(events.parse_failures.len(), 1);
+}
+
+#[test]
+fn failures_of_failed_transactions_dont_fail_the_block() {
+    let params = Params {
+        include_failed_transactions: true,
+        error_policy: ErrorPolicy::Strict,
+    };
+    let events = get_block_events(&block(), &params).unwrap();
+    assert_eq!(events.parse_failure_count, 1);
+    assert_eq!(events.parse_failures.len(), 0);
+}
@@ -0,0 +1,15 @@
+use proptest::prelude::*;
+
+use substreams_solana_utils::system_program::SystemInstruction;
+
+proptest! {
+    #[test]
+    fn unpack_instruction_is_total(data in prop::collection::vec(any::<u8>(), 0..256)) {
+        let _ = SystemInstruction::unpack(&data);
+    }
+
+    #[test]
+    fn unpack_tagged_instruction_is_total(tag in 0u32..16, data in prop::collection::vec(any::<u8>(), 0..256)) {
+        let _ = SystemInstruction::unpack(&[tag.to_le_bytes().as_slice(), data.as_slice()].concat());
+    }
+}